@@ -0,0 +1,50 @@
+//! Embeds all example inputs into the binary so example runs work without the source tree,
+//! and records the enabled cargo features so `--watch` can rebuild with the same ones.
+
+use std::path::Path;
+use std::{env, fs};
+
+fn main() {
+    let inputs_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs");
+    println!("cargo:rerun-if-changed={}", inputs_dir.display());
+
+    let mut entries = Vec::new();
+    if let Ok(years) = fs::read_dir(&inputs_dir) {
+        for year in years.flatten() {
+            let year_name = year.file_name().to_string_lossy().into_owned();
+            let Ok(files) = fs::read_dir(year.path().join("example")) else {
+                continue;
+            };
+            for file in files.flatten() {
+                let path = file.path();
+                if path.extension().is_none_or(|ext| ext != "txt") {
+                    continue;
+                }
+                let stem = path.file_stem().unwrap().to_string_lossy().into_owned();
+                entries.push((format!("{year_name}/{stem}"), path));
+            }
+        }
+    }
+    entries.sort();
+
+    let mut code = String::from("pub static EXAMPLES: &[(&str, &str)] = &[\n");
+    for (key, path) in entries {
+        code.push_str(&format!(
+            "    ({key:?}, include_str!({:?})),\n",
+            path.display()
+        ));
+    }
+    code.push_str("];\n");
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs");
+    fs::write(out, code).unwrap();
+
+    let mut features: Vec<String> = env::vars()
+        .filter_map(|(key, _)| {
+            let feature = key.strip_prefix("CARGO_FEATURE_")?;
+            Some(feature.to_lowercase().replace('_', "-"))
+        })
+        .collect();
+    features.sort();
+    println!("cargo:rustc-env=AOC_FEATURES={}", features.join(","));
+}
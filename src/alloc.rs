@@ -0,0 +1,110 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+/// Live bytes when the counters were last reset, so peak is reported per part
+static BASELINE: AtomicUsize = AtomicUsize::new(0);
+
+/// System allocator wrapper that counts allocations, installed by the binary with the
+/// `alloc-stats` feature
+pub struct CountingAlloc;
+
+impl CountingAlloc {
+    fn record(&self, size: usize) {
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        ALLOCATED.fetch_add(size, Relaxed);
+        let live = LIVE.fetch_add(size, Relaxed) + size;
+        PEAK.fetch_max(live, Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            self.record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            self.record(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        LIVE.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            LIVE.fetch_sub(layout.size(), Relaxed);
+            self.record(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Allocation counts since the last [`reset`]
+#[derive(Clone, Copy, Debug)]
+pub struct AllocStats {
+    pub allocations: usize,
+    /// Total bytes requested, including reallocations
+    pub allocated: usize,
+    /// Highest live memory above what was live at the reset
+    pub peak: usize,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "allocs: {}, allocated: {}, peak: {}",
+            self.allocations,
+            format_bytes(self.allocated),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+/// Start counting from zero, e.g. before running a part
+pub fn reset() {
+    let live = LIVE.load(Relaxed);
+    ALLOCATIONS.store(0, Relaxed);
+    ALLOCATED.store(0, Relaxed);
+    BASELINE.store(live, Relaxed);
+    PEAK.store(live, Relaxed);
+}
+
+/// Counts since the last [`reset`]
+pub fn stats() -> AllocStats {
+    AllocStats {
+        allocations: ALLOCATIONS.load(Relaxed),
+        allocated: ALLOCATED.load(Relaxed),
+        peak: PEAK.load(Relaxed).saturating_sub(BASELINE.load(Relaxed)),
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
+}
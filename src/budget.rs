@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use tabled::{Table, Tabled, settings::Style};
+
+use crate::manifest::Manifest;
+use crate::{Opt, SolutionFn, get_input_for_day, get_solution, time_solution};
+
+/// Runs per part; the median is compared against the budget
+const BUDGET_RUNS: usize = 5;
+
+#[derive(Tabled)]
+struct BudgetRow {
+    #[tabled(rename = "Year")]
+    year: u16,
+    #[tabled(rename = "Day")]
+    day: u8,
+    #[tabled(rename = "Part")]
+    part: u8,
+    #[tabled(rename = "Median")]
+    median: String,
+    #[tabled(rename = "Budget")]
+    budget: String,
+    #[tabled(rename = "Status")]
+    status: String,
+}
+
+/// Run every part with a time budget in the answers manifest on its real input and
+/// report whether all medians are within budget. `-d` limits the check to that day of
+/// the selected year. Parts whose real input isn't available are skipped, which is a
+/// failure with `--require-inputs`; a budget for a part that isn't implemented is a violation.
+pub fn check_budgets(opt: &Opt) -> Result<bool> {
+    let manifest = Manifest::load()?;
+    let mut input_opt = opt.clone();
+    input_opt.real = true;
+    input_opt.alt = None;
+
+    let mut rows = Vec::new();
+    let mut violations = 0;
+    let mut skipped = 0;
+    let mut timed = 0;
+
+    for ((year, day), entry) in manifest.days() {
+        let selected = opt
+            .day
+            .is_none_or(|d| (opt.normalized_year(), d) == (year, day));
+        if entry.budgets.is_empty() || !selected {
+            continue;
+        }
+        let input = get_input_for_day(&input_opt, year, day);
+
+        for (&part, &budget) in &entry.budgets {
+            let mut row = BudgetRow {
+                year,
+                day,
+                part,
+                median: "-".to_string(),
+                budget: format!("{budget:?}"),
+                status: String::new(),
+            };
+
+            let f = get_solution(year, day).and_then(|solution| solution.part(part));
+            match (f, &input) {
+                (None, _) => {
+                    violations += 1;
+                    row.status = "NOT IMPLEMENTED".to_string();
+                }
+                (Some(_), Err(e)) => {
+                    skipped += 1;
+                    // Keep the table narrow: the first line says why, the rest is hints
+                    let message = e.to_string();
+                    row.status = format!("skipped: {}", message.lines().next().unwrap_or_default());
+                }
+                (Some(f), Ok(input)) => {
+                    timed += 1;
+                    let median = median_time(f, input);
+                    row.median = format!("{median:?}");
+                    row.status = if median <= budget {
+                        "ok".to_string()
+                    } else {
+                        violations += 1;
+                        format!("OVER by {:?}", median - budget)
+                    };
+                }
+            }
+            rows.push(row);
+        }
+    }
+
+    if rows.is_empty() {
+        println!("No time budgets in {}", Manifest::path().display());
+        return Ok(true);
+    }
+
+    let total = rows.len();
+    println!("{}", Table::new(rows).with(Style::rounded()));
+    if skipped > 0 {
+        eprintln!(
+            "{skipped} of {total} budgeted parts skipped because their real input is not available"
+        );
+        if opt.require_inputs {
+            return Ok(false);
+        }
+    }
+    if violations > 0 {
+        eprintln!("{violations} of {total} budgeted parts over budget");
+        return Ok(false);
+    }
+    if timed == 0 {
+        println!("No budgeted parts were timed");
+    } else {
+        println!("All timed parts within budget");
+    }
+    Ok(true)
+}
+
+fn median_time(f: SolutionFn, input: &str) -> Duration {
+    let mut times: Vec<Duration> = (0..BUDGET_RUNS)
+        .map(|_| time_solution(f, input).1)
+        .collect();
+    times.sort_unstable();
+    times[times.len() / 2]
+}
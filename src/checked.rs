@@ -0,0 +1,77 @@
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use num::traits::{CheckedAdd, CheckedMul, CheckedSub};
+use num::{One, Zero};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+/// Overflows seen in the running part, by what overflowed, with how often
+static OVERFLOWS: Mutex<Vec<(&'static str, usize)>> = Mutex::new(Vec::new());
+
+/// Turn on overflow detection in the helpers below (`--checked`)
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Check whether overflow detection is on
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+fn record(what: &'static str) {
+    let mut overflows = OVERFLOWS.lock().unwrap();
+    match overflows.iter_mut().find(|(w, _)| *w == what) {
+        Some((_, count)) => *count += 1,
+        None => overflows.push((what, 1)),
+    }
+}
+
+/// Forget overflows from a previous part
+pub(crate) fn reset() {
+    OVERFLOWS.lock().unwrap().clear();
+}
+
+/// Overflows recorded since the last [`reset`], for the runner to report
+pub(crate) fn take_overflows() -> Vec<(&'static str, usize)> {
+    std::mem::take(&mut *OVERFLOWS.lock().unwrap())
+}
+
+/// `a + b`. With `--checked`, an overflow is recorded under `what` before falling back to
+/// the plain operation, so release builds still wrap but the runner reports it.
+/// Works for `BigInt`/`BigUint` too, which never overflow.
+pub fn add<T: CheckedAdd>(a: T, b: T, what: &'static str) -> T {
+    if enabled() && a.checked_add(&b).is_none() {
+        record(what);
+    }
+    a + b
+}
+
+/// `a - b`, recording overflow under `what` with `--checked` (see [`add`])
+pub fn sub<T: CheckedSub>(a: T, b: T, what: &'static str) -> T {
+    if enabled() && a.checked_sub(&b).is_none() {
+        record(what);
+    }
+    a - b
+}
+
+/// `a * b`, recording overflow under `what` with `--checked` (see [`add`])
+pub fn mul<T: CheckedMul>(a: T, b: T, what: &'static str) -> T {
+    if enabled() && a.checked_mul(&b).is_none() {
+        record(what);
+    }
+    a * b
+}
+
+/// Sum of `values`, recording overflow under `what` with `--checked`
+pub fn sum<T: CheckedAdd + Zero>(values: impl IntoIterator<Item = T>, what: &'static str) -> T {
+    values
+        .into_iter()
+        .fold(T::zero(), |acc, value| add(acc, value, what))
+}
+
+/// Product of `values`, recording overflow under `what` with `--checked`
+pub fn product<T: CheckedMul + One>(values: impl IntoIterator<Item = T>, what: &'static str) -> T {
+    values
+        .into_iter()
+        .fold(T::one(), |acc, value| mul(acc, value, what))
+}
@@ -0,0 +1,350 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{ArgAction, Parser, ValueEnum};
+#[cfg(feature = "net")]
+use reqwest::header::COOKIE;
+
+use crate::{Tag, embedded, encryption, visualize};
+
+#[derive(Clone, Parser)]
+#[command(name = "aoc")]
+#[command(about = "Advent of Code runner")]
+pub struct Opt {
+    /// Year (e.g., 25 or 2025). Defaults to current year.
+    #[arg(short, long, default_value_t = 2025)]
+    pub year: u16,
+
+    /// Day (1-25)
+    #[arg(short, long)]
+    pub day: Option<u8>,
+
+    /// Which part to run (1, 2, or both if omitted)
+    #[arg(short, long)]
+    pub part: Option<u8>,
+
+    /// Use real input instead of example
+    #[arg(short, long)]
+    pub real: bool,
+
+    /// Alternative input file name (without extension)
+    #[arg(short, long)]
+    pub alt: Option<String>,
+
+    /// Run a named implementation instead of the default one
+    #[arg(long, conflicts_with = "compare")]
+    pub variant: Option<String>,
+
+    /// Run every implementation of each part, check they agree and compare timings
+    #[arg(long)]
+    pub compare: bool,
+
+    /// Run each part N times and fail if the answers differ
+    #[arg(long, value_name = "N", conflicts_with = "compare")]
+    pub determinism: Option<usize>,
+
+    /// With --determinism, cycle the rayon thread count between runs
+    #[arg(long, requires = "determinism")]
+    pub vary_threads: bool,
+
+    /// With --determinism, run each time in a fresh process so hashers get new random seeds
+    #[arg(long, requires = "determinism")]
+    pub reseed: bool,
+
+    /// Print each part's answer, time and timing phases as a JSON line instead of text.
+    /// Python prototypes are not run.
+    #[arg(long, conflicts_with_all = ["compare", "determinism"])]
+    pub json: bool,
+
+    /// Report arithmetic overflow in solutions that use the `checked` helpers instead of
+    /// silently wrapping (release builds) or panicking (debug builds)
+    #[arg(long)]
+    pub checked: bool,
+
+    /// Run the day on every example file (dayN.txt and dayN_*.txt) and check the answers
+    /// listed in answers.json
+    #[arg(long, conflicts_with_all = ["real", "alt", "compare", "determinism"])]
+    pub examples: bool,
+
+    /// Time every part with a budget in answers.json on its real input and fail if any
+    /// median is over budget (use a release build)
+    #[arg(long)]
+    pub budgets: bool,
+
+    /// Fail --budgets when a budgeted part's real input is missing instead of skipping it
+    /// (for CI, where a missing input key would otherwise pass the gate)
+    #[arg(long, requires = "budgets")]
+    pub require_inputs: bool,
+
+    /// Run every implementation on N generated inputs and fail if they disagree or panic
+    #[arg(long, value_name = "N", conflicts_with_all = ["compare", "determinism", "scale"])]
+    pub fuzz: Option<usize>,
+
+    /// Time each part on generated inputs of doubling size, up to --size
+    #[arg(long, conflicts_with_all = ["compare", "determinism"])]
+    pub scale: bool,
+
+    /// Size of generated inputs for --fuzz (default 20), or the largest size for --scale (default 512)
+    #[arg(long, value_name = "N")]
+    pub size: Option<usize>,
+
+    /// Seed for generated inputs (random if omitted)
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// AOC session token (overrides .env and environment variable)
+    #[arg(long)]
+    pub session: Option<String>,
+
+    /// List all available solutions
+    #[arg(long)]
+    pub list: bool,
+
+    /// Only list days with this tag (repeatable; days must have all given tags)
+    #[arg(long, value_enum, requires = "list")]
+    pub tag: Vec<Tag>,
+
+    /// Log verbosity for solutions: -v info, -vv debug, -vvv trace (debug builds only)
+    #[arg(short, long, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Only log for this day
+    #[arg(long, value_name = "DAY")]
+    pub log_day: Option<u8>,
+
+    /// Draw visualizations from solutions that support them (to stderr)
+    #[arg(long)]
+    pub visualize: bool,
+
+    /// Frames per second for animated visualizations
+    #[arg(long, default_value_t = 10)]
+    pub fps: u32,
+
+    /// Export visualization frames to files under out/
+    #[arg(long, value_enum)]
+    pub export: Option<visualize::ExportFormat>,
+
+    /// Colours for exported frames, e.g. "#=ffffff,.=000000"
+    #[arg(long, requires = "export")]
+    pub palette: Option<String>,
+
+    /// Number of threads for parallel solutions (defaults to one per CPU)
+    #[arg(long, value_name = "N", conflicts_with = "serial")]
+    pub threads: Option<usize>,
+
+    /// Run parallel solutions on a single thread, for reproducible timings
+    #[arg(long)]
+    pub serial: bool,
+
+    /// Rebuild and re-run whenever the day's source or input file changes
+    #[arg(long)]
+    pub watch: bool,
+
+    /// Scaffold a new day solution file instead of running
+    #[arg(long)]
+    pub new: bool,
+
+    /// Template to scaffold a new day from (see the templates directory)
+    #[arg(long, value_enum, default_value_t = Template::Lines, requires = "new")]
+    pub template: Template,
+
+    /// Register part 2 in the scaffolded day instead of leaving it as an unregistered stub
+    #[arg(long, requires = "new")]
+    pub with_part2: bool,
+
+    /// Register a day's `_part2` stub as part 2 by rewriting its source file
+    #[arg(long)]
+    pub start_part2: bool,
+
+    /// Show a private leaderboard, from a local JSON file or a leaderboard id
+    #[arg(long, value_name = "FILE_OR_ID")]
+    pub leaderboard: Option<String>,
+
+    /// Hex key for encrypting real inputs (overrides .env and environment variable)
+    #[arg(long)]
+    pub input_key: Option<String>,
+
+    /// Serve solutions over HTTP (GET /solutions, POST /run/{year}/{day}/{part})
+    #[arg(long, value_name = "ADDR", num_args = 0..=1, default_missing_value = "127.0.0.1:3000")]
+    pub serve: Option<String>,
+
+    /// Start an interactive session for the selected day (run, bench, inspect, switch inputs)
+    #[arg(long)]
+    pub repl: bool,
+
+    /// Report the shape of the selected input (lines, grid size, characters, integers)
+    /// instead of running the solution
+    #[arg(long)]
+    pub inspect: bool,
+
+    /// Python interpreter for `dayN.py` prototypes (overrides .env and environment variable)
+    #[arg(long, value_name = "PATH")]
+    pub python: Option<String>,
+
+    /// Don't run a day's Python prototype alongside the Rust solution
+    #[arg(long, conflicts_with = "python")]
+    pub no_python: bool,
+
+    /// Re-encrypt all real inputs with the current key (decrypting with AOC_INPUT_KEY_OLD if set)
+    #[arg(long)]
+    pub rotate_inputs: bool,
+}
+
+/// Puzzle archetypes available for scaffolding new days
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Template {
+    /// One line per entry
+    Lines,
+    /// A 2D character grid
+    Grid,
+    /// Nodes and edges
+    Graph,
+    /// Numbers on each line
+    Numbers,
+    /// Blank-line-separated sections
+    Sections,
+}
+
+impl Template {
+    /// Name of the template file under `templates/` (without extension)
+    pub fn file_stem(&self) -> &'static str {
+        match self {
+            Template::Lines => "lines",
+            Template::Grid => "grid",
+            Template::Graph => "graph",
+            Template::Numbers => "numbers",
+            Template::Sections => "sections",
+        }
+    }
+}
+
+impl Opt {
+    /// Parse CLI arguments
+    pub fn get() -> Self {
+        // Load .env file if it exists (before parsing args)
+        let _ = dotenvy::dotenv();
+        Opt::parse()
+    }
+
+    /// Normalize year (25 -> 2025)
+    pub fn normalized_year(&self) -> u16 {
+        if self.year < 100 {
+            2000 + self.year
+        } else {
+            self.year
+        }
+    }
+}
+
+/// Configure the global rayon pool from `--threads`/`--serial`.
+/// Must be called before any solution runs.
+#[cfg(feature = "parallel")]
+pub fn configure_threads(opt: &Opt) -> Result<()> {
+    let threads = if opt.serial { Some(1) } else { opt.threads };
+    if let Some(threads) = threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn configure_threads(_opt: &Opt) -> Result<()> {
+    Ok(())
+}
+
+/// Get input for a specific year/day
+pub fn get_input_for_day(opt: &Opt, year: u16, day: u8) -> Result<String> {
+    let path = make_path(year, day, opt);
+
+    let content = match (path.exists(), opt.real) {
+        (true, _) => encryption::decrypt_if_needed(opt, fs::read_to_string(path)?)?,
+        (false, false) => path
+            .file_stem()
+            .and_then(|stem| embedded::get_example(year, &stem.to_string_lossy()))
+            .map(str::to_string)
+            .ok_or_else(|| anyhow::anyhow!("Example input not found: {}", path.display()))?,
+        (false, true) => download_and_save(opt, path, year, day)?,
+    };
+
+    // Normalize line endings (CRLF -> LF)
+    Ok(content.replace("\r\n", "\n"))
+}
+
+/// Path of the input file selected by `opt` (real, example or alternative example)
+pub fn make_path(year: u16, day: u8, opt: &Opt) -> PathBuf {
+    let filename = format!("day{day}");
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    path.push("inputs");
+    path.push(format!("y{year}"));
+    path.push(if opt.real { "real" } else { "example" });
+    path.push(if opt.real || opt.alt.is_none() {
+        &filename
+    } else {
+        opt.alt.as_ref().unwrap()
+    });
+    path.set_extension("txt");
+
+    path
+}
+
+#[cfg(not(feature = "net"))]
+fn download_and_save(_opt: &Opt, path: PathBuf, _year: u16, _day: u8) -> Result<String> {
+    anyhow::bail!(
+        "Real input not found: {} (built without the `net` feature, so it can't be downloaded)",
+        path.display()
+    )
+}
+
+#[cfg(feature = "net")]
+fn download_and_save(opt: &Opt, path: PathBuf, year: u16, day: u8) -> Result<String> {
+    // Create parent directories if needed
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let resp = download_input(opt, year, day)?;
+    fs::write(&path, encryption::encrypt_if_configured(opt, &resp)?)?;
+    Ok(resp)
+}
+
+#[cfg(feature = "net")]
+fn download_input(opt: &Opt, year: u16, day: u8) -> Result<String> {
+    let client = reqwest::blocking::Client::new();
+    let resp = client
+        .get(make_url(year, day))
+        .header(
+            COOKIE,
+            String::from("session=") + get_session_token(opt)?.as_str(),
+        )
+        .send()?
+        .text()?;
+
+    Ok(resp)
+}
+
+#[cfg(feature = "net")]
+fn make_url(year: u16, day: u8) -> String {
+    format!("https://adventofcode.com/{year}/day/{day}/input")
+}
+
+/// Get session token with priority: CLI arg > .env > env var
+#[cfg(feature = "net")]
+pub(crate) fn get_session_token(opt: &Opt) -> Result<String> {
+    // 1. Check CLI argument
+    if let Some(session) = &opt.session {
+        return Ok(session.clone());
+    }
+
+    // 2. Check environment variable (dotenvy already loaded .env)
+    std::env::var("AOC_SESSION").map_err(|_| {
+        anyhow::anyhow!(
+            "AOC_SESSION not found. Set it via:\n\
+             - CLI: --session <token>\n\
+             - .env file: AOC_SESSION=<token>\n\
+             - Environment variable: export AOC_SESSION=<token>"
+        )
+    })
+}
@@ -0,0 +1,186 @@
+use std::process::Command;
+use std::time::{Duration, Instant};
+use std::{env, thread};
+
+use anyhow::{Context, Result, bail};
+use tabled::{Table, Tabled, settings::Style};
+
+use crate::watch::parse_answers;
+use crate::{Answer, Opt, SolutionFn, time_solution};
+
+/// Flags that control the determinism check itself and must not reach child runs
+const OWN_FLAGS: [&str; 3] = ["--determinism", "--vary-threads", "--reseed"];
+/// Flags replaced by `--threads <N>` when varying thread counts in child runs
+const THREAD_FLAGS: [&str; 2] = ["--threads", "--serial"];
+
+#[derive(Tabled)]
+struct RunRow {
+    #[tabled(rename = "Run")]
+    run: usize,
+    #[tabled(rename = "Threads")]
+    threads: String,
+    #[tabled(rename = "Part")]
+    part: u8,
+    #[tabled(rename = "Answer")]
+    answer: String,
+    #[tabled(rename = "Time")]
+    time: String,
+    #[tabled(rename = "Status")]
+    status: &'static str,
+}
+
+/// Run each selected part `opt.determinism` times, print every answer and report whether
+/// they all agree. `--vary-threads` cycles the rayon thread count between runs and
+/// `--reseed` runs each one in a fresh process so every hasher gets a new random seed.
+pub fn check_determinism(opt: &Opt, input: &str, parts: &[(u8, SolutionFn)]) -> Result<bool> {
+    let runs = opt.determinism.unwrap_or(1);
+    if opt.vary_threads && !cfg!(feature = "parallel") {
+        bail!("--vary-threads needs the `parallel` feature");
+    }
+    let thread_counts = if opt.vary_threads {
+        thread_counts()
+    } else {
+        Vec::new()
+    };
+
+    let mut rows = Vec::new();
+    let mut agree = true;
+
+    for &(part, f) in parts {
+        let mut expected: Option<Answer> = None;
+        for run in 1..=runs {
+            let threads =
+                (!thread_counts.is_empty()).then(|| thread_counts[(run - 1) % thread_counts.len()]);
+            let (answer, duration) = if opt.reseed {
+                run_child(part, threads)?
+            } else {
+                run_in_process(f, input, threads)?
+            };
+
+            let status = match &expected {
+                None => "reference",
+                Some(expected) if *expected == answer => "ok",
+                Some(_) => {
+                    agree = false;
+                    "MISMATCH"
+                }
+            };
+            rows.push(RunRow {
+                run,
+                threads: threads.map_or_else(|| "-".to_string(), |t| t.to_string()),
+                part,
+                answer: answer.to_string(),
+                time: format!("{duration:?}"),
+                status,
+            });
+            expected.get_or_insert(answer);
+        }
+    }
+
+    println!("{}", Table::new(rows).with(Style::rounded()));
+    if agree {
+        println!("All {runs} runs agree");
+    } else {
+        eprintln!("Answers differ between runs");
+    }
+    Ok(agree)
+}
+
+/// Thread counts to cycle through: powers of two up to 8, plus the number of CPUs
+fn thread_counts() -> Vec<usize> {
+    let cpus = thread::available_parallelism().map_or(1, |n| n.get());
+    let mut counts = vec![1, 2, 4, 8, cpus];
+    counts.sort_unstable();
+    counts.dedup();
+    counts
+}
+
+#[cfg(feature = "parallel")]
+fn run_in_process(
+    f: SolutionFn,
+    input: &str,
+    threads: Option<usize>,
+) -> Result<(Answer, Duration)> {
+    match threads {
+        Some(threads) => {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()?;
+            Ok(pool.install(|| time_solution(f, input)))
+        }
+        None => Ok(time_solution(f, input)),
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+fn run_in_process(
+    f: SolutionFn,
+    input: &str,
+    _threads: Option<usize>,
+) -> Result<(Answer, Duration)> {
+    Ok(time_solution(f, input))
+}
+
+/// Run one part in a fresh copy of the runner, with the same arguments minus the
+/// determinism flags. The child skips any Python prototype so the last answer it
+/// prints is the Rust one.
+fn run_child(part: u8, threads: Option<usize>) -> Result<(Answer, Duration)> {
+    let mut skip: Vec<&str> = OWN_FLAGS.to_vec();
+    skip.extend(["--part", "-p", "--python", "--no-python"]);
+    if threads.is_some() {
+        skip.extend(THREAD_FLAGS);
+    }
+
+    let mut args = strip_flags(env::args().skip(1), &skip);
+    args.extend(["--part".to_string(), part.to_string()]);
+    args.push("--no-python".to_string());
+    if let Some(threads) = threads {
+        args.extend(["--threads".to_string(), threads.to_string()]);
+    }
+
+    let exe = env::current_exe().context("locating the runner executable")?;
+    let start = Instant::now();
+    let output = Command::new(exe)
+        .args(&args)
+        .output()
+        .context("running solution")?;
+    let duration = start.elapsed();
+    if !output.status.success() {
+        bail!(
+            "run failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match parse_answers(&stdout).pop() {
+        Some((_, answer)) => Ok((Answer(answer), duration)),
+        None => bail!("no answer in output:\n{stdout}"),
+    }
+}
+
+/// Remove the given flags (and their values) from an argument list. Handles `--flag value`,
+/// `--flag=value` and `-p1` forms; flags without a value only take the next argument
+/// if it doesn't look like another flag.
+fn strip_flags(args: impl Iterator<Item = String>, flags: &[&str]) -> Vec<String> {
+    let mut kept = Vec::new();
+    let mut args = args.peekable();
+
+    while let Some(arg) = args.next() {
+        let name = arg.split_once('=').map_or(arg.as_str(), |(name, _)| name);
+        let matched = flags.iter().find(|&&flag| {
+            name == flag || (flag.len() == 2 && !flag.starts_with("--") && name.starts_with(flag))
+        });
+        match matched {
+            Some(&flag) if name == flag && !arg.contains('=') => {
+                if args.peek().is_some_and(|next| !next.starts_with('-')) {
+                    args.next();
+                }
+            }
+            Some(_) => {}
+            None => kept.push(arg),
+        }
+    }
+
+    kept
+}
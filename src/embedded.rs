@@ -0,0 +1,25 @@
+// Generated by build.rs from inputs/y<year>/example/*.txt
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
+
+/// Look up an example input embedded at build time by year and file name (without extension)
+pub fn get_example(year: u16, name: &str) -> Option<&'static str> {
+    let key = format!("y{year}/{name}");
+    EXAMPLES
+        .binary_search_by(|(k, _)| (*k).cmp(key.as_str()))
+        .ok()
+        .map(|i| EXAMPLES[i].1)
+}
+
+/// Names of the embedded example inputs for a day: `day<day>` and any `day<day>_<suffix>`
+pub fn list_examples(year: u16, day: u8) -> Vec<&'static str> {
+    let prefix = format!("y{year}/");
+    let stem = format!("day{day}");
+    EXAMPLES
+        .iter()
+        .filter_map(|(key, _)| key.strip_prefix(prefix.as_str()))
+        .filter(|name| {
+            name.strip_prefix(stem.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('_'))
+        })
+        .collect()
+}
@@ -0,0 +1,171 @@
+use std::path::PathBuf;
+use std::{env, fs};
+
+use anyhow::{Context, Result, anyhow, bail};
+use chacha20poly1305::aead::{Aead, Generate, KeyInit};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+
+use crate::Opt;
+
+/// Prefix marking an encrypted input file; the rest is `<hex nonce>:<hex ciphertext>`
+const MAGIC: &str = "aoc-encrypted:v1:";
+
+/// Check whether file contents were written by [`encrypt`]
+pub fn is_encrypted(contents: &str) -> bool {
+    contents.starts_with(MAGIC)
+}
+
+/// Encrypt an input with a fresh random nonce
+pub fn encrypt(key: &[u8; 32], plaintext: &str) -> Result<String> {
+    let cipher = XChaCha20Poly1305::new(key.into());
+    let nonce = XNonce::generate();
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_bytes())
+        .map_err(|_| anyhow!("encryption failed"))?;
+
+    Ok(format!(
+        "{MAGIC}{}:{}\n",
+        to_hex(nonce.as_slice()),
+        to_hex(&ciphertext)
+    ))
+}
+
+/// Decrypt contents produced by [`encrypt`]
+pub fn decrypt(key: &[u8; 32], contents: &str) -> Result<String> {
+    let payload = contents
+        .trim_end()
+        .strip_prefix(MAGIC)
+        .context("input is not encrypted")?;
+    let (nonce, ciphertext) = payload
+        .split_once(':')
+        .context("malformed encrypted input")?;
+
+    let nonce = XNonce::try_from(from_hex(nonce)?.as_slice())
+        .map_err(|_| anyhow!("malformed encrypted input nonce"))?;
+    let cipher = XChaCha20Poly1305::new(key.into());
+    let plaintext = cipher
+        .decrypt(&nonce, from_hex(ciphertext)?.as_slice())
+        .map_err(|_| anyhow!("decryption failed (wrong AOC_INPUT_KEY?)"))?;
+
+    Ok(String::from_utf8(plaintext)?)
+}
+
+/// Get input encryption key with priority: CLI arg > .env > env var.
+/// Returns `None` if no key is configured; an empty `AOC_INPUT_KEY` counts as unset.
+pub fn get_input_key(opt: &Opt) -> Result<Option<[u8; 32]>> {
+    match &opt.input_key {
+        Some(key) => parse_key(key).map(Some),
+        None => env::var("AOC_INPUT_KEY")
+            .ok()
+            .filter(|key| !key.trim().is_empty())
+            .map(|key| parse_key(&key))
+            .transpose(),
+    }
+}
+
+fn require_input_key(opt: &Opt) -> Result<[u8; 32]> {
+    get_input_key(opt)?.ok_or_else(|| {
+        anyhow!(
+            "AOC_INPUT_KEY not found. Generate one with `openssl rand -hex 32` and set it via:\n\
+             - CLI: --input-key <key>\n\
+             - .env file: AOC_INPUT_KEY=<key>\n\
+             - Environment variable: export AOC_INPUT_KEY=<key>"
+        )
+    })
+}
+
+fn parse_key(hex: &str) -> Result<[u8; 32]> {
+    from_hex(hex.trim())?
+        .try_into()
+        .map_err(|_| anyhow!("input key must be 32 bytes (64 hex characters)"))
+}
+
+/// Decrypt file contents if they are encrypted, otherwise return them unchanged
+pub fn decrypt_if_needed(opt: &Opt, contents: String) -> Result<String> {
+    if is_encrypted(&contents) {
+        decrypt(&require_input_key(opt)?, &contents)
+    } else {
+        Ok(contents)
+    }
+}
+
+/// Encrypt contents for writing if a key is configured, otherwise return them unchanged
+pub fn encrypt_if_configured(opt: &Opt, contents: &str) -> Result<String> {
+    match get_input_key(opt)? {
+        Some(key) => encrypt(&key, contents),
+        None => Ok(contents.to_string()),
+    }
+}
+
+/// Re-encrypt every real input with the current key.
+/// Already encrypted files are decrypted with `AOC_INPUT_KEY_OLD` if set, else the current key;
+/// files that already decrypt with the current key are accepted too, so an interrupted rotation
+/// can be re-run. Plaintext files are encrypted. Every file is decrypted before any is written,
+/// and each is replaced through a temporary file. Returns the paths that were rewritten.
+pub fn rotate_inputs(opt: &Opt) -> Result<Vec<PathBuf>> {
+    let key = require_input_key(opt)?;
+    let old_key = match env::var("AOC_INPUT_KEY_OLD") {
+        Ok(old) if !old.trim().is_empty() => {
+            parse_key(&old).context("parsing AOC_INPUT_KEY_OLD")?
+        }
+        _ => key,
+    };
+
+    let inputs_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("inputs");
+    let mut inputs = Vec::new();
+
+    for year_dir in fs::read_dir(&inputs_dir)? {
+        let real_dir = year_dir?.path().join("real");
+        if !real_dir.is_dir() {
+            continue;
+        }
+
+        for entry in fs::read_dir(&real_dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "txt") {
+                continue;
+            }
+
+            let contents =
+                fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+            let plaintext = if is_encrypted(&contents) {
+                decrypt(&old_key, &contents)
+                    .or_else(|_| decrypt(&key, &contents))
+                    .with_context(|| path.display().to_string())?
+            } else {
+                contents
+            };
+            inputs.push((path, plaintext));
+        }
+    }
+
+    if inputs.is_empty() {
+        bail!("No real inputs found under {}", inputs_dir.display());
+    }
+
+    let mut rewritten = Vec::new();
+    for (path, plaintext) in inputs {
+        let temp = path.with_extension("txt.tmp");
+        fs::write(&temp, encrypt(&key, &plaintext)?)
+            .with_context(|| format!("writing {}", temp.display()))?;
+        fs::rename(&temp, &path).with_context(|| format!("replacing {}", path.display()))?;
+        rewritten.push(path);
+    }
+
+    rewritten.sort();
+    Ok(rewritten)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn from_hex(hex: &str) -> Result<Vec<u8>> {
+    if !hex.is_ascii() || !hex.len().is_multiple_of(2) {
+        bail!("invalid hex string");
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).context("invalid hex string"))
+        .collect()
+}
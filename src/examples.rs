@@ -0,0 +1,172 @@
+use anyhow::{Result, bail};
+use tabled::{Table, Tabled, settings::Style};
+
+use crate::manifest::Manifest;
+use crate::python;
+use crate::stress::catch_panic;
+use crate::{Opt, embedded, get_input_for_day, get_solution, time_solution};
+
+#[derive(Tabled)]
+struct ExampleRow {
+    #[tabled(rename = "File")]
+    file: &'static str,
+    #[tabled(rename = "Part")]
+    part: u8,
+    #[tabled(rename = "Solver")]
+    solver: String,
+    #[tabled(rename = "Answer")]
+    answer: String,
+    #[tabled(rename = "Expected")]
+    expected: String,
+    #[tabled(rename = "Time")]
+    time: String,
+    #[tabled(rename = "Status")]
+    status: &'static str,
+}
+
+/// Run the day's parts on every `day<N>` and `day<N>_*` example and print a table of the
+/// results. Examples listed in the answers manifest only run the parts they have answers
+/// for and are checked against them; other examples run every part. The day's Python
+/// prototype, unless `--no-python` is given, is run on each example too and checked
+/// against the same answers; if it fails to run, its rows are skipped with a warning.
+/// Returns whether no answer was wrong and no part panicked.
+pub fn run_examples(opt: &Opt, year: u16, day: u8) -> Result<bool> {
+    let Some(solution) = get_solution(year, day) else {
+        bail!("No solution found for {year} day {day}");
+    };
+    let names = embedded::list_examples(year, day);
+    if names.is_empty() {
+        bail!("No example inputs for {year} day {day}");
+    }
+    let manifest = Manifest::load()?;
+    let expected_answers = manifest.day(year, day).map(|entry| &entry.examples);
+    let script = if opt.no_python {
+        None
+    } else {
+        python::find_script(year, day)
+    };
+    let python_answers = |part: u8| script.as_ref().is_some_and(|s| s.parts.contains(&part));
+
+    let mut rows = Vec::new();
+    let mut passed = true;
+    let mut python_failed = false;
+    for name in names {
+        let mut example_opt = opt.clone();
+        example_opt.real = false;
+        example_opt.alt = Some(name.to_string());
+        let input = get_input_for_day(&example_opt, year, day)?;
+
+        let expected = expected_answers.and_then(|examples| examples.get(name));
+        let parts: Vec<u8> = match expected {
+            Some(answers) => answers.keys().copied().collect(),
+            None => [1, 2]
+                .into_iter()
+                .filter(|&p| solution.part(p).is_some() || python_answers(p))
+                .collect(),
+        };
+
+        for &part in &parts {
+            let expected = expected.and_then(|answers| answers.get(&part)?.as_ref());
+            let mut row = ExampleRow {
+                file: name,
+                part,
+                solver: "rust".to_string(),
+                answer: "-".to_string(),
+                expected: expected.cloned().unwrap_or_else(|| "-".to_string()),
+                time: "-".to_string(),
+                status: "-",
+            };
+
+            let Some(f) = solution.part(part) else {
+                // A part only the prototype answers is covered by its row below
+                if python_answers(part) {
+                    continue;
+                }
+                passed = false;
+                row.status = "NOT IMPLEMENTED";
+                rows.push(row);
+                continue;
+            };
+            match catch_panic(|| time_solution(f, &input)) {
+                Ok((answer, duration)) => {
+                    row.time = format!("{duration:?}");
+                    row.status = match expected {
+                        Some(expected) if *expected == answer.0 => "ok",
+                        Some(_) => {
+                            passed = false;
+                            "WRONG"
+                        }
+                        None => "-",
+                    };
+                    row.answer = answer.0;
+                }
+                Err(message) => {
+                    passed = false;
+                    row.answer = format!("panicked: {message}");
+                    row.status = "PANIC";
+                }
+            }
+            rows.push(row);
+        }
+
+        let Some(script) = &script else {
+            continue;
+        };
+        let python_parts: Vec<u8> = parts.into_iter().filter(|&p| python_answers(p)).collect();
+        if python_parts.is_empty() {
+            continue;
+        }
+        let result = python::run_script(&example_opt, script, &input);
+        for part in python_parts {
+            let expected = expected.and_then(|answers| answers.get(&part)?.as_ref());
+            let mut row = ExampleRow {
+                file: name,
+                part,
+                solver: script.file_name(),
+                answer: "-".to_string(),
+                expected: expected.cloned().unwrap_or_else(|| "-".to_string()),
+                time: "-".to_string(),
+                status: "-",
+            };
+            match &result {
+                Ok((answers, duration)) => {
+                    row.time = format!("{duration:?}");
+                    let answer = answers.iter().find(|(p, _)| *p == part).map(|(_, a)| &a.0);
+                    row.status = match (answer, expected) {
+                        (None, _) => {
+                            passed = false;
+                            "NO ANSWER"
+                        }
+                        (Some(answer), Some(expected)) if answer == expected => "ok",
+                        (Some(_), Some(_)) => {
+                            passed = false;
+                            "WRONG"
+                        }
+                        (Some(_), None) => "-",
+                    };
+                    row.answer = answer.cloned().unwrap_or_else(|| "-".to_string());
+                }
+                Err(e) => {
+                    // Like a normal run, a prototype that can't run is a warning, not a failure
+                    python_failed = true;
+                    // Only the last line of a traceback fits in the table
+                    let message = e.to_string();
+                    row.answer = format!("failed: {}", message.lines().last().unwrap_or_default());
+                    row.status = "skipped";
+                }
+            }
+            rows.push(row);
+        }
+    }
+
+    println!("{}", Table::new(rows).with(Style::rounded()));
+    if python_failed {
+        eprintln!(
+            "Warning: the Python prototype could not be run; set the interpreter with --python or AOC_PYTHON, or skip it with --no-python"
+        );
+    }
+    if !passed {
+        eprintln!("Some examples failed");
+    }
+    Ok(passed)
+}
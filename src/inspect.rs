@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+
+use tabled::{Table, Tabled, settings::Style};
+
+/// Shape of a puzzle input, to decide how to parse it
+pub struct InputReport {
+    pub lines: usize,
+    /// Number of lines of each length
+    pub line_lengths: BTreeMap<usize, usize>,
+    /// Width and height if every line has the same length
+    pub grid: Option<(usize, usize)>,
+    pub sections: usize,
+    pub chars: BTreeMap<char, usize>,
+    pub integers: Vec<i128>,
+    /// Numbers that didn't fit in an i128
+    pub overflowed: usize,
+    pub anomalies: Vec<String>,
+}
+
+#[derive(Tabled)]
+struct PropertyRow {
+    #[tabled(rename = "Property")]
+    name: &'static str,
+    #[tabled(rename = "Value")]
+    value: String,
+}
+
+#[derive(Tabled)]
+struct CharRow {
+    #[tabled(rename = "Char")]
+    char: String,
+    #[tabled(rename = "Count")]
+    count: usize,
+}
+
+/// Gather statistics about an input as returned by `get_input_for_day`
+pub fn inspect_input(input: &str) -> InputReport {
+    let lines: Vec<&str> = input.lines().collect();
+
+    let mut line_lengths = BTreeMap::new();
+    for line in &lines {
+        *line_lengths.entry(line.chars().count()).or_insert(0) += 1;
+    }
+
+    let grid = match (line_lengths.len(), line_lengths.keys().next()) {
+        (1, Some(&width)) if width > 0 => Some((width, lines.len())),
+        _ => None,
+    };
+
+    let sections = input
+        .split("\n\n")
+        .filter(|section| !section.trim().is_empty())
+        .count();
+
+    let mut chars = BTreeMap::new();
+    for c in input.chars() {
+        *chars.entry(c).or_insert(0) += 1;
+    }
+
+    let (integers, overflowed) = find_integers(input);
+
+    InputReport {
+        lines: lines.len(),
+        line_lengths,
+        grid,
+        sections,
+        chars,
+        integers,
+        overflowed,
+        anomalies: whitespace_anomalies(input, &lines),
+    }
+}
+
+/// Find all integers, treating `-` as a sign unless it follows a digit (so `3-5` is 3 and 5)
+fn find_integers(input: &str) -> (Vec<i128>, usize) {
+    let bytes = input.as_bytes();
+    let mut integers = Vec::new();
+    let mut overflowed = 0;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let negative = start > 0
+            && bytes[start - 1] == b'-'
+            && (start < 2 || !bytes[start - 2].is_ascii_digit());
+
+        match input[start..i].parse::<i128>() {
+            Ok(n) if negative => integers.push(-n),
+            Ok(n) => integers.push(n),
+            Err(_) => overflowed += 1,
+        }
+    }
+
+    (integers, overflowed)
+}
+
+fn whitespace_anomalies(input: &str, lines: &[&str]) -> Vec<String> {
+    let mut anomalies = Vec::new();
+
+    if input.starts_with(char::is_whitespace) {
+        anomalies.push("starts with whitespace".to_string());
+    }
+    if !input.is_empty() && !input.ends_with('\n') {
+        anomalies.push("no trailing newline".to_string());
+    }
+    let trailing_blank = lines.iter().rev().take_while(|l| l.is_empty()).count();
+    if trailing_blank > 0 {
+        anomalies.push(format!("{trailing_blank} trailing blank line(s)"));
+    }
+
+    let mut count_lines = |description: &str, pred: fn(&str) -> bool| {
+        let count = lines.iter().filter(|l| pred(l)).count();
+        if count > 0 {
+            anomalies.push(format!("{count} line(s) {description}"));
+        }
+    };
+    count_lines("with leading whitespace", |l| {
+        l.starts_with(char::is_whitespace)
+    });
+    count_lines("with trailing whitespace", |l| {
+        l.ends_with(char::is_whitespace)
+    });
+    count_lines("containing tabs", |l| l.contains('\t'));
+    count_lines("containing carriage returns", |l| l.contains('\r'));
+
+    anomalies
+}
+
+/// Smallest integer type that holds every value in `min..=max`
+fn integer_width(min: i128, max: i128) -> &'static str {
+    let widths: [(&str, i128, i128); 4] = if min >= 0 {
+        [
+            ("u8", 0, u8::MAX.into()),
+            ("u16", 0, u16::MAX.into()),
+            ("u32", 0, u32::MAX.into()),
+            ("u64", 0, u64::MAX.into()),
+        ]
+    } else {
+        [
+            ("i8", i8::MIN.into(), i8::MAX.into()),
+            ("i16", i16::MIN.into(), i16::MAX.into()),
+            ("i32", i32::MIN.into(), i32::MAX.into()),
+            ("i64", i64::MIN.into(), i64::MAX.into()),
+        ]
+    };
+
+    widths
+        .iter()
+        .find(|&&(_, lo, hi)| min >= lo && max <= hi)
+        .map_or(if min >= 0 { "u128" } else { "i128" }, |&(name, _, _)| name)
+}
+
+/// Show a character so whitespace stays visible
+fn escape_char(c: char) -> String {
+    match c {
+        ' ' => "' '".to_string(),
+        _ => c.escape_default().to_string(),
+    }
+}
+
+/// Render an input report as a summary table followed by a character histogram
+pub fn render_report(report: &InputReport) -> String {
+    let lengths = if report.line_lengths.len() <= 8 {
+        report
+            .line_lengths
+            .iter()
+            .map(|(len, count)| format!("{len} ({count}x)"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    } else {
+        let mut all: Vec<usize> = report
+            .line_lengths
+            .iter()
+            .flat_map(|(&len, &count)| std::iter::repeat_n(len, count))
+            .collect();
+        all.sort_unstable();
+        format!(
+            "min {}, median {}, max {} ({} distinct)",
+            all[0],
+            all[all.len() / 2],
+            all[all.len() - 1],
+            report.line_lengths.len()
+        )
+    };
+
+    let integers = match (report.integers.iter().min(), report.integers.iter().max()) {
+        (Some(&min), Some(&max)) => format!(
+            "{} (min {min}, max {max}, fits {})",
+            report.integers.len(),
+            integer_width(min, max)
+        ),
+        _ => "none".to_string(),
+    };
+    let integers = if report.overflowed > 0 {
+        format!("{integers}, {} too large for i128", report.overflowed)
+    } else {
+        integers
+    };
+
+    let rows = vec![
+        PropertyRow {
+            name: "Lines",
+            value: report.lines.to_string(),
+        },
+        PropertyRow {
+            name: "Line lengths",
+            value: lengths,
+        },
+        PropertyRow {
+            name: "Grid",
+            value: match report.grid {
+                Some((width, height)) => format!("{width} x {height}"),
+                None => "no".to_string(),
+            },
+        },
+        PropertyRow {
+            name: "Sections",
+            value: report.sections.to_string(),
+        },
+        PropertyRow {
+            name: "Integers",
+            value: integers,
+        },
+        PropertyRow {
+            name: "Whitespace",
+            value: if report.anomalies.is_empty() {
+                "ok".to_string()
+            } else {
+                report.anomalies.join("\n")
+            },
+        },
+    ];
+
+    let mut chars: Vec<_> = report.chars.iter().filter(|&(&c, _)| c != '\n').collect();
+    chars.sort_by_key(|&(&c, &count)| (std::cmp::Reverse(count), c));
+    let char_rows = chars.into_iter().map(|(&c, &count)| CharRow {
+        char: escape_char(c),
+        count,
+    });
+
+    format!(
+        "{}\n{}",
+        Table::new(rows).with(Style::rounded()),
+        Table::new(char_rows).with(Style::rounded())
+    )
+}
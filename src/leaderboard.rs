@@ -0,0 +1,210 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+#[cfg(feature = "net")]
+use reqwest::header::COOKIE;
+use serde_json::Value;
+use tabled::{Table, Tabled, settings::Style};
+
+use crate::Opt;
+#[cfg(feature = "net")]
+use crate::get_session_token;
+
+/// Puzzles unlock at midnight EST (UTC-5)
+const UNLOCK_OFFSET_SECS: i64 = 5 * 60 * 60;
+const SECS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// A single member of a private leaderboard
+pub struct Member {
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u64,
+    /// Star timestamps indexed by `day - 1`: (part 1, part 2)
+    pub completions: [(Option<i64>, Option<i64>); 25],
+}
+
+#[derive(Tabled)]
+struct StandingRow {
+    #[tabled(rename = "Rank")]
+    rank: usize,
+    #[tabled(rename = "Member")]
+    name: String,
+    #[tabled(rename = "Score")]
+    local_score: u64,
+    #[tabled(rename = "Stars")]
+    stars: u64,
+    #[tabled(rename = "Days")]
+    days: String,
+}
+
+#[derive(Tabled)]
+struct CompletionRow {
+    #[tabled(rename = "Member")]
+    name: String,
+    #[tabled(rename = "Day")]
+    day: u8,
+    #[tabled(rename = "Part 1")]
+    part1: String,
+    #[tabled(rename = "Part 2")]
+    part2: String,
+    #[tabled(rename = "Delta")]
+    delta: String,
+}
+
+/// Load a private leaderboard from a local JSON file or, if `source` is not a file,
+/// from the AoC API using it as the leaderboard id
+pub fn load_leaderboard(opt: &Opt, year: u16, source: &str) -> Result<Vec<Member>> {
+    let json = if Path::new(source).is_file() {
+        fs::read_to_string(source).with_context(|| format!("reading {source}"))?
+    } else {
+        download_leaderboard(opt, year, source)?
+    };
+
+    let value: Value = serde_json::from_str(&json).context("parsing leaderboard JSON")?;
+    parse_members(&value)
+}
+
+#[cfg(not(feature = "net"))]
+fn download_leaderboard(_opt: &Opt, _year: u16, id: &str) -> Result<String> {
+    anyhow::bail!(
+        "Leaderboard file not found: {id} (built without the `net` feature, so it can't be downloaded)"
+    )
+}
+
+#[cfg(feature = "net")]
+fn download_leaderboard(opt: &Opt, year: u16, id: &str) -> Result<String> {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit()) {
+        anyhow::bail!("Leaderboard source is neither a file nor a numeric leaderboard id: {id}");
+    }
+
+    let client = reqwest::blocking::Client::new();
+    let resp = client
+        .get(format!(
+            "https://adventofcode.com/{year}/leaderboard/private/view/{id}.json"
+        ))
+        .header(
+            COOKIE,
+            String::from("session=") + get_session_token(opt)?.as_str(),
+        )
+        .send()?
+        .error_for_status()?
+        .text()?;
+
+    Ok(resp)
+}
+
+fn parse_members(value: &Value) -> Result<Vec<Member>> {
+    let members = value["members"]
+        .as_object()
+        .context("leaderboard JSON has no `members` object")?;
+
+    let mut members: Vec<Member> = members
+        .values()
+        .map(|m| {
+            let mut completions = [(None, None); 25];
+            if let Some(days) = m["completion_day_level"].as_object() {
+                for (day, parts) in days {
+                    let Some(idx) = day.parse::<usize>().ok().filter(|d| (1..=25).contains(d))
+                    else {
+                        continue;
+                    };
+                    completions[idx - 1] = (
+                        parts["1"]["get_star_ts"].as_i64(),
+                        parts["2"]["get_star_ts"].as_i64(),
+                    );
+                }
+            }
+
+            Member {
+                name: m["name"]
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("(anonymous user #{})", m["id"])),
+                local_score: m["local_score"].as_u64().unwrap_or(0),
+                stars: m["stars"].as_u64().unwrap_or(0),
+                completions,
+            }
+        })
+        .collect();
+
+    members.sort_by(|a, b| {
+        b.local_score
+            .cmp(&a.local_score)
+            .then(b.stars.cmp(&a.stars))
+            .then(a.name.cmp(&b.name))
+    });
+
+    Ok(members)
+}
+
+/// Render the standings table and the per-member completion times.
+/// If `day` is given, only that day's completion times are shown.
+pub fn render_leaderboard(members: &[Member], year: u16, day: Option<u8>) -> String {
+    let standings = members
+        .iter()
+        .enumerate()
+        .map(|(i, m)| StandingRow {
+            rank: i + 1,
+            name: m.name.clone(),
+            local_score: m.local_score,
+            stars: m.stars,
+            days: m
+                .completions
+                .iter()
+                .map(|c| match c {
+                    (Some(_), Some(_)) => '*',
+                    (Some(_), None) => '+',
+                    _ => '.',
+                })
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+
+    let completions = members
+        .iter()
+        .flat_map(|m| {
+            m.completions
+                .iter()
+                .zip(1u8..)
+                .filter(move |&(_, d)| day.is_none_or(|day| day == d))
+                .filter_map(move |(&(p1, p2), d)| {
+                    let p1 = p1?;
+                    let unlock = unlock_time(year, d);
+                    Some(CompletionRow {
+                        name: m.name.clone(),
+                        day: d,
+                        part1: format_duration(p1 - unlock),
+                        part2: p2.map_or_else(String::new, |p2| format_duration(p2 - unlock)),
+                        delta: p2.map_or_else(String::new, |p2| format_duration(p2 - p1)),
+                    })
+                })
+        })
+        .collect::<Vec<_>>();
+
+    format!(
+        "{}\n(* both parts, + part 1 only)\n\n{}",
+        Table::new(standings).with(Style::rounded()),
+        Table::new(completions).with(Style::rounded())
+    )
+}
+
+/// Unix timestamp at which the puzzle for the given day unlocks
+fn unlock_time(year: u16, day: u8) -> i64 {
+    days_from_civil(year as i64, 12, day as i64) * SECS_PER_DAY + UNLOCK_OFFSET_SECS
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's algorithm)
+fn days_from_civil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+fn format_duration(secs: i64) -> String {
+    let secs = secs.max(0);
+    format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
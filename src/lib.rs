@@ -1,18 +1,100 @@
-use clap::Parser;
+//! Advent of Code solutions with a CLI runner.
+//!
+//! Other crates can call the solvers directly through [`solve`] and iterate the registry
+//! with [`solutions`]. Build with `default-features = false` plus the wanted `y<year>`
+//! features to leave out the CLI (`cli`) and downloading (`net`):
+//!
+//! ```no_run
+//! let answer = adventofcode::solve(2025, 1, 1, "L68\nR48\n")?;
+//! println!("{answer}");
+//!
+//! for solution in adventofcode::solutions() {
+//!     println!("{} day {}", solution.year, solution.day);
+//! }
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::fmt;
 use std::sync::LazyLock;
-use std::time::Instant;
-use std::{env, fs};
-
-use anyhow::Result;
-use reqwest::header::COOKIE;
-
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, bail};
+#[cfg(feature = "cli")]
+use clap::ValueEnum;
+
+#[cfg(feature = "alloc-stats")]
+pub mod alloc;
+#[cfg(feature = "cli")]
+pub mod budget;
+pub mod checked;
+#[cfg(feature = "cli")]
+mod cli;
+#[cfg(feature = "cli")]
+pub mod determinism;
+pub mod embedded;
+#[cfg(feature = "cli")]
+pub mod encryption;
+#[cfg(feature = "cli")]
+pub mod examples;
+#[cfg(feature = "cli")]
+pub mod inspect;
+#[cfg(feature = "cli")]
+pub mod leaderboard;
+pub mod log;
+#[cfg(feature = "cli")]
+pub mod manifest;
+#[cfg(feature = "cli")]
+pub mod python;
+#[cfg(feature = "cli")]
+pub mod repl;
+#[cfg(feature = "cli")]
+pub mod serve;
 pub mod solutions;
+#[cfg(feature = "cli")]
+pub mod stress;
+pub mod timing;
 pub mod utils;
+pub mod visualize;
+#[cfg(feature = "cli")]
+pub mod watch;
+
+#[cfg(all(feature = "cli", feature = "net"))]
+pub(crate) use cli::get_session_token;
+#[cfg(feature = "cli")]
+pub use cli::{Opt, Template, configure_threads, get_input_for_day, make_path};
+
+/// The answer to a puzzle part, as it would be submitted
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Answer(pub String);
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer(value.to_string())
+                }
+            }
+        )*
+    };
+}
 
-/// Function type for solution parts
-pub type SolutionFn = fn(&str);
+impl_answer_from!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, String, &str
+);
+// Arbitrary precision, for answers that outgrow the primitive types
+impl_answer_from!(num::BigInt, num::BigUint);
+
+/// Function type for solution parts. Solutions return any type that converts into an
+/// [`Answer`]; `register_day!` and `register_variant!` wrap them into this type.
+pub type SolutionFn = fn(&str) -> Answer;
 
 /// A registered day solution
 pub struct DaySolution {
@@ -20,10 +102,126 @@ pub struct DaySolution {
     pub day: u8,
     pub part1: SolutionFn,
     pub part2: Option<SolutionFn>,
+    pub meta: Option<Metadata>,
+}
+
+/// Optional description of a day's puzzle, shown and filtered by `--list`
+#[derive(Clone, Copy, Debug)]
+pub struct Metadata {
+    pub title: &'static str,
+    pub tags: &'static [Tag],
+    /// Helpers and algorithms the solution relies on, e.g. "bfs" or "GridUtils"
+    pub techniques: &'static [&'static str],
+    pub notes: &'static str,
+}
+
+impl Metadata {
+    /// Base for struct update syntax, e.g. `Metadata { title: "...", ..Metadata::EMPTY }`
+    pub const EMPTY: Metadata = Metadata {
+        title: "",
+        tags: &[],
+        techniques: &[],
+        notes: "",
+    };
+}
+
+/// Kinds of puzzle, for finding similar days
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+pub enum Tag {
+    /// 2D character grids
+    Grid,
+    /// Nodes and edges, path finding
+    Graph,
+    /// Dynamic programming and memoization
+    Dp,
+    /// Stepping a system forward in time
+    Simulation,
+    /// Coordinates, distances, areas and ranges
+    Geometry,
+    /// Most of the work is reading the input
+    Parsing,
+    /// Number theory and closed-form arithmetic
+    Math,
+}
+
+impl Tag {
+    /// Name of the tag as accepted by `--tag`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Tag::Grid => "grid",
+            Tag::Graph => "graph",
+            Tag::Dp => "dp",
+            Tag::Simulation => "simulation",
+            Tag::Geometry => "geometry",
+            Tag::Parsing => "parsing",
+            Tag::Math => "math",
+        }
+    }
+}
+
+/// A named alternative implementation of one part of a day
+pub struct Variant {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub name: &'static str,
+    pub solution: SolutionFn,
+}
+
+/// Name of the implementation registered with `register_day!`
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// Function type for input generators: a random valid puzzle input of roughly `size`
+/// entries (stones, boxes, ranges, ... whatever the day's input is a list of)
+pub type GeneratorFn = fn(&mut fastrand::Rng, usize) -> String;
+
+/// A registered input generator for a day, used by `--fuzz` and `--scale`
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    pub generate: GeneratorFn,
 }
 
 // Allow solutions to register themselves
 inventory::collect!(DaySolution);
+inventory::collect!(Variant);
+inventory::collect!(Generator);
+
+impl DaySolution {
+    /// The implementation registered with `register_day!` for a part
+    pub fn part(&self, part: u8) -> Option<SolutionFn> {
+        match part {
+            1 => Some(self.part1),
+            2 => self.part2,
+            _ => None,
+        }
+    }
+
+    /// All implementations of a part: the default one first, then named variants by name
+    pub fn variants(&self, part: u8) -> Vec<(&'static str, SolutionFn)> {
+        let mut variants: Vec<_> = inventory::iter::<Variant>
+            .into_iter()
+            .filter(|v| v.year == self.year && v.day == self.day && v.part == part)
+            .map(|v| (v.name, v.solution))
+            .collect();
+        variants.sort_by_key(|&(name, _)| name);
+
+        self.part(part)
+            .map(|f| (DEFAULT_VARIANT, f))
+            .into_iter()
+            .chain(variants)
+            .collect()
+    }
+
+    /// A part's implementation by variant name
+    pub fn variant(&self, part: u8, name: &str) -> Option<SolutionFn> {
+        self.variants(part)
+            .into_iter()
+            .find(|&(n, _)| n == name)
+            .map(|(_, f)| f)
+    }
+}
 
 /// Lookup table built from all registered solutions
 pub static SOLUTIONS: LazyLock<HashMap<(u16, u8), &'static DaySolution>> = LazyLock::new(|| {
@@ -38,6 +236,14 @@ pub fn get_solution(year: u16, day: u8) -> Option<&'static DaySolution> {
     SOLUTIONS.get(&(year, day)).copied()
 }
 
+/// Get the input generator for a year and day
+pub fn get_generator(year: u16, day: u8) -> Option<GeneratorFn> {
+    inventory::iter::<Generator>
+        .into_iter()
+        .find(|g| g.year == year && g.day == day)
+        .map(|g| g.generate)
+}
+
 /// List all registered solutions
 pub fn list_solutions() -> Vec<(u16, u8)> {
     let mut keys: Vec<_> = SOLUTIONS.keys().copied().collect();
@@ -45,170 +251,160 @@ pub fn list_solutions() -> Vec<(u16, u8)> {
     keys
 }
 
-/// Macro to register a day solution
+/// All registered solutions, ordered by year and day
+pub fn solutions() -> Vec<&'static DaySolution> {
+    list_solutions()
+        .into_iter()
+        .map(|key| SOLUTIONS[&key])
+        .collect()
+}
+
+/// Solve one part of a day with its default implementation.
+///
+/// `input` is the puzzle input as downloaded; Windows line endings are normalized.
+/// Fails if no solution is registered for the year, day and part.
+pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<Answer> {
+    let Some(solution) = get_solution(year, day) else {
+        bail!("No solution found for {year} day {day}");
+    };
+    let Some(f) = solution.part(part) else {
+        bail!("Part {part} not implemented for {year} day {day}");
+    };
+
+    if input.contains('\r') {
+        Ok(f(&input.replace("\r\n", "\n")))
+    } else {
+        Ok(f(input))
+    }
+}
+
+/// Macro to register a day solution, optionally followed by `; Metadata { ... }`
 #[macro_export]
 macro_rules! register_day {
+    (@meta) => {
+        None
+    };
+    (@meta $meta:expr) => {
+        Some($meta)
+    };
     // With both parts
-    ($year:expr, $day:expr, $part1:expr, $part2:expr) => {
+    ($year:expr, $day:expr, $part1:expr, $part2:expr $(; $meta:expr)?) => {
         inventory::submit! {
             $crate::DaySolution {
                 year: $year,
                 day: $day,
-                part1: $part1,
-                part2: Some($part2),
+                part1: |input| $crate::Answer::from($part1(input)),
+                part2: Some(|input| $crate::Answer::from($part2(input))),
+                meta: $crate::register_day!(@meta $($meta)?),
             }
         }
     };
     // With only part 1
-    ($year:expr, $day:expr, $part1:expr) => {
+    ($year:expr, $day:expr, $part1:expr $(; $meta:expr)?) => {
         inventory::submit! {
             $crate::DaySolution {
                 year: $year,
                 day: $day,
-                part1: $part1,
+                part1: |input| $crate::Answer::from($part1(input)),
                 part2: None,
+                meta: $crate::register_day!(@meta $($meta)?),
             }
         }
     };
 }
 
-#[derive(Parser)]
-#[command(name = "aoc")]
-#[command(about = "Advent of Code runner")]
-pub struct Opt {
-    /// Year (e.g., 25 or 2025). Defaults to current year.
-    #[arg(short, long, default_value_t = 2025)]
-    pub year: u16,
-
-    /// Day (1-25)
-    #[arg(short, long)]
-    pub day: Option<u8>,
-
-    /// Which part to run (1, 2, or both if omitted)
-    #[arg(short, long)]
-    pub part: Option<u8>,
-
-    /// Use real input instead of example
-    #[arg(short, long)]
-    pub real: bool,
-
-    /// Alternative input file name (without extension)
-    #[arg(short, long)]
-    pub alt: Option<String>,
-
-    /// AOC session token (overrides .env and environment variable)
-    #[arg(long)]
-    pub session: Option<String>,
-
-    /// List all available solutions
-    #[arg(long)]
-    pub list: bool,
-
-    /// Scaffold a new day solution file instead of running
-    #[arg(long)]
-    pub new: bool,
-}
-
-impl Opt {
-    /// Parse CLI arguments
-    pub fn get() -> Self {
-        // Load .env file if it exists (before parsing args)
-        let _ = dotenvy::dotenv();
-        Opt::parse()
-    }
-
-    /// Normalize year (25 -> 2025)
-    pub fn normalized_year(&self) -> u16 {
-        if self.year < 100 {
-            2000 + self.year
-        } else {
-            self.year
+/// Macro to register a named alternative implementation of a part,
+/// e.g. `register_variant!(2023, 5, 2, "ranges", part2_ranges);`
+#[macro_export]
+macro_rules! register_variant {
+    ($year:expr, $day:expr, $part:expr, $name:expr, $solution:expr) => {
+        inventory::submit! {
+            $crate::Variant {
+                year: $year,
+                day: $day,
+                part: $part,
+                name: $name,
+                solution: |input| $crate::Answer::from($solution(input)),
+            }
         }
-    }
-}
-
-/// Run a solution function with timing
-pub fn runner(f: SolutionFn, input: &str) {
-    println!("---");
-    let start = Instant::now();
-    f(input);
-    let duration = start.elapsed();
-    println!("--- {duration:?}")
+    };
 }
 
-/// Get input for a specific year/day
-pub fn get_input_for_day(opt: &Opt, year: u16, day: u8) -> Result<String> {
-    let path = make_path(year, day, opt);
-
-    let content = match (path.exists(), opt.real) {
-        (true, _) => fs::read_to_string(path)?,
-        (false, false) => anyhow::bail!("Example input not found: {}", path.display()),
-        (false, true) => download_and_save(opt, path, year, day)?,
+/// Macro to register a random input generator for a day,
+/// e.g. `register_generator!(2024, 11, generate);`
+#[macro_export]
+macro_rules! register_generator {
+    ($year:expr, $day:expr, $generate:expr) => {
+        inventory::submit! {
+            $crate::Generator {
+                year: $year,
+                day: $day,
+                generate: $generate,
+            }
+        }
     };
-
-    // Normalize line endings (CRLF -> LF)
-    Ok(content.replace("\r\n", "\n"))
 }
 
-fn make_path(year: u16, day: u8, opt: &Opt) -> PathBuf {
-    let filename = format!("day{day}");
-    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-
-    path.push("inputs");
-    path.push(format!("y{year}"));
-    path.push(if opt.real { "real" } else { "example" });
-    path.push(if opt.real || opt.alt.is_none() {
-        &filename
-    } else {
-        opt.alt.as_ref().unwrap()
-    });
-    path.set_extension("txt");
-
-    path
+/// Run a solution function, returning its answer and how long it took
+pub fn time_solution(f: SolutionFn, input: &str) -> (Answer, Duration) {
+    let start = Instant::now();
+    let answer = f(input);
+    (answer, start.elapsed())
 }
 
-fn download_and_save(opt: &Opt, path: PathBuf, year: u16, day: u8) -> Result<String> {
-    // Create parent directories if needed
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)?;
+/// Run a solution function with timing, printing its answer
+pub fn runner(f: SolutionFn, input: &str, day: u8, part: u8) -> Answer {
+    println!("---");
+    let (answer, duration, phases) = run_measured(f, input);
+    #[cfg(feature = "alloc-stats")]
+    let alloc_stats = alloc::stats();
+    println!("Day {day} Part {part}: {answer}");
+
+    #[allow(unused_mut)]
+    let mut summary = format!("--- {duration:?}");
+    #[cfg(feature = "parallel")]
+    summary.push_str(&format!(" (threads: {})", rayon::current_num_threads()));
+    #[cfg(feature = "alloc-stats")]
+    summary.push_str(&format!(" [{alloc_stats}]"));
+    println!("{summary}");
+    if !phases.is_empty() {
+        println!("{}", timing::render_phases(&phases, duration));
     }
-    let resp = download_input(opt, year, day)?;
-    fs::write(&path, resp.as_bytes())?;
-    Ok(resp)
+    report_overflows();
+    answer
 }
 
-fn download_input(opt: &Opt, year: u16, day: u8) -> Result<String> {
-    let client = reqwest::blocking::Client::new();
-    let resp = client
-        .get(make_url(year, day))
-        .header(
-            COOKIE,
-            String::from("session=") + get_session_token(opt)?.as_str(),
-        )
-        .send()?
-        .text()?;
-
-    Ok(resp)
+/// Run a solution function with timing, printing its answer, time and phases as one
+/// JSON line
+pub fn json_runner(f: SolutionFn, input: &str, year: u16, day: u8, part: u8) -> Answer {
+    let (answer, duration, phases) = run_measured(f, input);
+    println!(
+        "{}",
+        serde_json::json!({
+            "year": year,
+            "day": day,
+            "part": part,
+            "answer": answer.to_string(),
+            "duration_ns": duration.as_nanos() as u64,
+            "phases": timing::phases_json(&phases),
+        })
+    );
+    report_overflows();
+    answer
 }
 
-fn make_url(year: u16, day: u8) -> String {
-    format!("https://adventofcode.com/{year}/day/{day}/input")
+/// Time a solution function with fresh allocation, phase and overflow tracking
+fn run_measured(f: SolutionFn, input: &str) -> (Answer, Duration, Vec<timing::Phase>) {
+    #[cfg(feature = "alloc-stats")]
+    alloc::reset();
+    timing::reset();
+    checked::reset();
+    let (answer, duration) = time_solution(f, input);
+    (answer, duration, timing::take_phases(Instant::now()))
 }
 
-/// Get session token with priority: CLI arg > .env > env var
-fn get_session_token(opt: &Opt) -> Result<String> {
-    // 1. Check CLI argument
-    if let Some(session) = &opt.session {
-        return Ok(session.clone());
+fn report_overflows() {
+    for (what, count) in checked::take_overflows() {
+        eprintln!("Overflow in {what} ({count} times); the answer may have wrapped");
     }
-
-    // 2. Check environment variable (dotenvy already loaded .env)
-    env::var("AOC_SESSION").map_err(|_| {
-        anyhow::anyhow!(
-            "AOC_SESSION not found. Set it via:\n\
-             - CLI: --session <token>\n\
-             - .env file: AOC_SESSION=<token>\n\
-             - Environment variable: export AOC_SESSION=<token>"
-        )
-    })
 }
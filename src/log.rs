@@ -0,0 +1,72 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Log levels, selected with `-v` (info), `-vv` (debug) and `-vvv` (trace)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Level {
+    Info = 1,
+    Debug = 2,
+    Trace = 3,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        })
+    }
+}
+
+/// Most verbose level that gets written; 0 disables logging
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// Set up logging for a run of `day`. Logging stays off if `log_day` selects another day.
+pub fn init(verbosity: u8, log_day: Option<u8>, day: u8) {
+    if verbosity > 0 && !cfg!(debug_assertions) {
+        eprintln!("Note: logging is compiled out of release builds");
+    }
+
+    let level = if log_day.is_some_and(|d| d != day) {
+        0
+    } else {
+        verbosity.min(Level::Trace as u8)
+    };
+    MAX_LEVEL.store(level, Ordering::Relaxed);
+}
+
+/// Check whether messages at `level` are written
+pub fn enabled(level: Level) -> bool {
+    cfg!(debug_assertions) && level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// Write a message at the given level to stderr.
+/// Arguments are only evaluated when the level is enabled, and never in release builds.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if cfg!(debug_assertions) && $crate::log::enabled($level) {
+            eprintln!("[{}] {}", $level, format_args!($($arg)+));
+        }
+    };
+}
+
+/// Log at info level (`-v`)
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+/// Log at debug level (`-vv`)
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+/// Log at trace level (`-vvv`)
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
@@ -1,11 +1,32 @@
-use adventofcode::{Opt, SOLUTIONS, get_input_for_day, get_solution, list_solutions, runner};
+use adventofcode::budget::check_budgets;
+use adventofcode::determinism::check_determinism;
+use adventofcode::encryption::rotate_inputs;
+use adventofcode::examples::run_examples;
+use adventofcode::inspect::{inspect_input, render_report};
+use adventofcode::leaderboard::{load_leaderboard, render_leaderboard};
+use adventofcode::manifest::{self, Manifest};
+use adventofcode::python;
+use adventofcode::repl::Repl;
+use adventofcode::serve::serve;
+use adventofcode::stress::{fuzz, scale};
+use adventofcode::visualize::{self, Palette};
+use adventofcode::watch::watch;
+use adventofcode::{
+    Answer, DaySolution, Metadata, Opt, SOLUTIONS, Tag, configure_threads, get_input_for_day,
+    get_solution, json_runner, list_solutions, runner, time_solution,
+};
 use anyhow::{Context, Result, bail};
+use regex::Regex;
 use std::{
     fs,
     path::{Path, PathBuf},
 };
 use tabled::{Table, Tabled, settings::Style};
 
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOC: adventofcode::alloc::CountingAlloc = adventofcode::alloc::CountingAlloc;
+
 #[derive(Tabled)]
 struct SolutionRow {
     #[tabled(rename = "Year")]
@@ -13,12 +34,35 @@ struct SolutionRow {
     #[tabled(rename = "Day")]
     day: u8,
     #[tabled(rename = "Parts")]
-    parts: &'static str,
+    parts: String,
+    #[tabled(rename = "Title")]
+    title: &'static str,
+    #[tabled(rename = "Tags")]
+    tags: String,
+    #[tabled(rename = "Techniques")]
+    techniques: String,
+}
+
+#[derive(Tabled)]
+struct VariantRow {
+    #[tabled(rename = "Part")]
+    part: u8,
+    #[tabled(rename = "Variant")]
+    variant: &'static str,
+    #[tabled(rename = "Answer")]
+    answer: String,
+    #[tabled(rename = "Time")]
+    time: String,
+    #[tabled(rename = "Status")]
+    status: &'static str,
 }
 
 fn main() {
     let opt = Opt::get();
     let year = opt.normalized_year();
+    if opt.checked {
+        adventofcode::checked::enable();
+    }
 
     if opt.new {
         if let Err(e) = scaffold_day(&opt, year) {
@@ -28,19 +72,104 @@ fn main() {
         return;
     }
 
+    if opt.start_part2 {
+        if let Err(e) = start_part2(&opt, year) {
+            eprintln!("Failed to start part 2: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if opt.rotate_inputs {
+        match rotate_inputs(&opt) {
+            Ok(paths) => {
+                for path in &paths {
+                    println!("Encrypted {}", path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to rotate inputs: {e}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if let Some(source) = &opt.leaderboard {
+        match load_leaderboard(&opt, year, source) {
+            Ok(members) => println!("{}", render_leaderboard(&members, year, opt.day)),
+            Err(e) => {
+                eprintln!("Failed to load leaderboard: {e}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if let Some(addr) = &opt.serve {
+        if let Err(e) = configure_threads(&opt).and_then(|()| serve(addr)) {
+            eprintln!("Failed to serve: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if opt.budgets {
+        match configure_threads(&opt).and_then(|()| check_budgets(&opt)) {
+            Ok(true) => {}
+            Ok(false) => std::process::exit(1),
+            Err(e) => {
+                eprintln!("Failed to check budgets: {e}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
     if opt.list {
-        let solutions = list_solutions();
+        let scripts = if opt.no_python {
+            Vec::new()
+        } else {
+            python::list_scripts()
+        };
+        let mut solutions = list_solutions();
+        solutions.extend(scripts.iter().map(|s| (s.year, s.day)));
+        solutions.sort_unstable();
+        solutions.dedup();
         if solutions.is_empty() {
             println!("No solutions available yet.");
             return;
         }
 
         let mut rows = Vec::new();
+        let mut notes = Vec::new();
         let mut last_year = 0;
 
         for (y, d) in solutions {
-            let sol = SOLUTIONS.get(&(y, d)).unwrap();
-            let parts = if sol.part2.is_some() { "1, 2" } else { "1" };
+            if opt.day.is_some_and(|day| day != d) {
+                continue;
+            }
+            let sol = SOLUTIONS.get(&(y, d));
+            let meta = sol.and_then(|sol| sol.meta).unwrap_or(Metadata::EMPTY);
+            if !opt.tag.iter().all(|tag| meta.tags.contains(tag)) {
+                continue;
+            }
+
+            let script = scripts.iter().find(|s| (s.year, s.day) == (y, d));
+            let parts = [1, 2]
+                .into_iter()
+                .filter_map(|p| {
+                    let rust = sol.is_some_and(|sol| sol.part(p).is_some());
+                    let python = script.is_some_and(|s| s.parts.contains(&p));
+                    match (rust, python) {
+                        (true, true) => Some(format!("{p} (+py)")),
+                        (true, false) => Some(p.to_string()),
+                        (false, true) => Some(format!("{p} (py)")),
+                        (false, false) => None,
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
             let year_str = if y != last_year {
                 last_year = y;
                 y.to_string()
@@ -51,11 +180,33 @@ fn main() {
                 year: year_str,
                 day: d,
                 parts,
+                title: meta.title,
+                tags: meta
+                    .tags
+                    .iter()
+                    .map(Tag::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                techniques: meta.techniques.join(", "),
             });
+            if !meta.notes.is_empty() {
+                notes.push(format!("{y} day {d}: {}", meta.notes));
+            }
+        }
+
+        if rows.is_empty() {
+            println!("No solutions match.");
+            return;
         }
 
         let table = Table::new(rows).with(Style::rounded()).to_string();
         println!("{table}");
+        // Notes are too long for the table, so only show them when listing a single day
+        if opt.day.is_some() {
+            for note in notes {
+                println!("{note}");
+            }
+        }
         return;
     }
 
@@ -65,11 +216,93 @@ fn main() {
         std::process::exit(1);
     };
 
-    let Some(solution) = get_solution(year, day) else {
+    if opt.watch {
+        if let Err(e) = watch(&opt, year, day) {
+            eprintln!("Failed to watch: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if opt.repl {
+        if let Err(e) = configure_threads(&opt).and_then(|()| Repl::new(&opt, year, day).run()) {
+            eprintln!("REPL failed: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if opt.examples {
+        match configure_threads(&opt).and_then(|()| run_examples(&opt, year, day)) {
+            Ok(true) => {}
+            Ok(false) => std::process::exit(1),
+            Err(e) => {
+                eprintln!("Failed to run examples: {e}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if let Some(runs) = opt.fuzz {
+        match configure_threads(&opt).and_then(|()| fuzz(&opt, year, day, runs)) {
+            Ok(true) => {}
+            Ok(false) => std::process::exit(1),
+            Err(e) => {
+                eprintln!("Failed to fuzz: {e}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    if opt.scale {
+        if let Err(e) = configure_threads(&opt).and_then(|()| scale(&opt, year, day)) {
+            eprintln!("Failed to scale: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if opt.inspect {
+        match get_input_for_day(&opt, year, day) {
+            Ok(input) => println!("{}", render_report(&inspect_input(&input))),
+            Err(e) => {
+                eprintln!("Failed to get input: {e}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    let solution = get_solution(year, day);
+    let script = if opt.no_python || opt.json {
+        None
+    } else {
+        python::find_script(year, day)
+    };
+    if solution.is_none() && script.is_none() {
         eprintln!("No solution found for {year} day {day}");
         eprintln!("Run with --list to see available solutions");
         std::process::exit(1);
+    }
+
+    adventofcode::log::init(opt.verbose, opt.log_day, day);
+    let export = match opt.palette.as_deref().map(Palette::parse).transpose() {
+        Ok(palette) => opt
+            .export
+            .map(|format| (format, palette.unwrap_or_default())),
+        Err(e) => {
+            eprintln!("Invalid palette: {e}");
+            std::process::exit(1);
+        }
     };
+    visualize::init(opt.visualize, opt.fps, export);
+
+    if let Err(e) = configure_threads(&opt) {
+        eprintln!("Failed to configure threads: {e}");
+        std::process::exit(1);
+    }
 
     let input = match get_input_for_day(&opt, year, day) {
         Ok(input) => input,
@@ -79,23 +312,182 @@ fn main() {
         }
     };
 
-    match opt.part {
-        Some(1) => runner(solution.part1, &input),
-        Some(2) => {
-            if let Some(part2) = solution.part2 {
-                runner(part2, &input);
-            } else {
-                eprintln!("Part 2 not implemented for {year} day {day}");
+    // Parts come from the Rust solution, the Python prototype, or both
+    let rust_parts: Vec<u8> = solution.map_or_else(Vec::new, |solution| {
+        [1, 2]
+            .into_iter()
+            .filter(|&p| solution.part(p).is_some())
+            .collect()
+    });
+    let python_parts = script.as_ref().map_or(&[][..], |script| &script.parts);
+    let implemented = |part: &u8| rust_parts.contains(part) || python_parts.contains(part);
+    let parts: Vec<u8> = match opt.part {
+        Some(part @ (1 | 2)) => {
+            if !implemented(&part) {
+                eprintln!("Part {part} not implemented for {year} day {day}");
                 std::process::exit(1);
             }
+            vec![part]
         }
-        None | Some(_) => {
-            runner(solution.part1, &input);
-            if let Some(part2) = solution.part2 {
-                runner(part2, &input);
+        _ => [1, 2].into_iter().filter(implemented).collect(),
+    };
+
+    let Some(solution) = solution.filter(|_| parts.iter().any(|p| rust_parts.contains(p))) else {
+        if opt.compare || opt.determinism.is_some() {
+            eprintln!("No Rust solution for {year} day {day}");
+            std::process::exit(1);
+        }
+        if let Some(script) = &script
+            && !run_python(&opt, script, &input, &parts, &[])
+        {
+            std::process::exit(1);
+        }
+        return;
+    };
+    let rust_selected: Vec<u8> = parts
+        .iter()
+        .copied()
+        .filter(|p| rust_parts.contains(p))
+        .collect();
+
+    if opt.compare {
+        if !compare_variants(solution, &input, &rust_selected) {
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let mut selected = Vec::new();
+    for &part in &rust_selected {
+        let f = match &opt.variant {
+            Some(name) => match solution.variant(part, name) {
+                Some(f) => f,
+                None => {
+                    let available = solution
+                        .variants(part)
+                        .iter()
+                        .map(|&(name, _)| name)
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    eprintln!(
+                        "No variant `{name}` for {year} day {day} part {part} (available: {available})"
+                    );
+                    std::process::exit(1);
+                }
+            },
+            None => solution.part(part).unwrap(),
+        };
+        selected.push((part, f));
+    }
+
+    if opt.determinism.is_some() {
+        match check_determinism(&opt, &input, &selected) {
+            Ok(true) => {}
+            Ok(false) => std::process::exit(1),
+            Err(e) => {
+                eprintln!("Failed to check determinism: {e}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    let mut answers = Vec::new();
+    for (part, f) in selected {
+        visualize::start_part(year, day, part);
+        let answer = if opt.json {
+            json_runner(f, &input, year, day, part)
+        } else {
+            runner(f, &input, day, part)
+        };
+        answers.push((part, answer));
+        visualize::finish_part();
+    }
+
+    if let Some(script) = &script
+        && !run_python(&opt, script, &input, &parts, &answers)
+    {
+        std::process::exit(1);
+    }
+}
+
+/// Run a day's Python prototype for whichever of `parts` it answers, print its answers
+/// and report whether they match the Rust answers for the same parts. A prototype that
+/// can't be run only prints a warning and counts as agreeing
+fn run_python(
+    opt: &Opt,
+    script: &python::Script,
+    input: &str,
+    parts: &[u8],
+    rust_answers: &[(u8, Answer)],
+) -> bool {
+    if !parts.iter().any(|p| script.parts.contains(p)) {
+        return true;
+    }
+
+    let (answers, duration) = match python::run_script(opt, script, input) {
+        Ok(result) => result,
+        Err(e) => {
+            // A missing interpreter or package isn't a wrong answer, so don't fail the run
+            eprintln!("Warning: could not run Python prototype: {e}");
+            eprintln!(
+                "Set the interpreter with --python or AOC_PYTHON, or skip it with --no-python"
+            );
+            return true;
+        }
+    };
+
+    let mut agree = true;
+    for (part, answer) in answers.iter().filter(|(p, _)| parts.contains(p)) {
+        println!("---");
+        println!("Day {} Part {part}: {answer}", script.day);
+        println!("--- {duration:?} (python: {})", script.file_name());
+        match rust_answers.iter().find(|(p, _)| p == part) {
+            Some((_, rust)) if rust == answer => println!("Matches the Rust answer"),
+            Some((_, rust)) => {
+                eprintln!("MISMATCH: Rust answered {rust}, Python answered {answer}");
+                agree = false;
             }
+            None => {}
         }
     }
+    agree
+}
+
+/// Run every variant of the given parts, print a timing table and report whether
+/// all variants of each part agree with the default implementation
+fn compare_variants(solution: &DaySolution, input: &str, parts: &[u8]) -> bool {
+    let mut rows = Vec::new();
+    let mut agree = true;
+
+    for &part in parts {
+        let mut expected: Option<Answer> = None;
+        for (name, f) in solution.variants(part) {
+            let (answer, duration) = time_solution(f, input);
+            let status = match &expected {
+                None => "reference",
+                Some(expected) if *expected == answer => "ok",
+                Some(_) => {
+                    agree = false;
+                    "MISMATCH"
+                }
+            };
+            rows.push(VariantRow {
+                part,
+                variant: name,
+                answer: answer.to_string(),
+                time: format!("{duration:?}"),
+                status,
+            });
+            expected.get_or_insert(answer);
+        }
+    }
+
+    println!("{}", Table::new(rows).with(Style::rounded()));
+    if !agree {
+        eprintln!("Variants disagree on the answer");
+    }
+    agree
 }
 
 fn scaffold_day(opt: &Opt, year: u16) -> Result<()> {
@@ -122,17 +514,95 @@ fn scaffold_day(opt: &Opt, year: u16) -> Result<()> {
         );
     }
 
-    let template = make_day_template(year, day);
+    let template = make_day_template(opt, year, day)?;
     fs::write(&day_path, template).with_context(|| format!("writing {}", day_path.display()))?;
-
     println!("Created {}", day_path.display());
+
+    let example_path = manifest_dir
+        .join("inputs")
+        .join(format!("y{year}"))
+        .join("example")
+        .join(format!("day{day}.txt"));
+    if !example_path.exists() {
+        fs::create_dir_all(example_path.parent().unwrap())
+            .context("creating example inputs directory")?;
+        fs::write(&example_path, "")
+            .with_context(|| format!("writing {}", example_path.display()))?;
+        println!("Created {}", example_path.display());
+    }
+
     println!(
-        "Next: add inputs under inputs/y{year}/example/day{day}.txt and run with: cargo run -- --year {year} --day {day}",
+        "Next: paste the example into {} and run with: cargo run -- --year {year} --day {day}",
+        example_path.display()
     );
 
     Ok(())
 }
 
+/// Rename the `_part2` stub to `part2`, register it and add a placeholder for its example
+/// answer to the manifest, refusing if the file doesn't have exactly the shape the
+/// scaffold produces
+fn start_part2(opt: &Opt, year: u16) -> Result<()> {
+    let Some(day) = opt.day else {
+        bail!("--start-part2 requires --day <day> (1-25)");
+    };
+
+    let day_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("solutions")
+        .join(format!("y{year}"))
+        .join(format!("day{day}.rs"));
+    let contents =
+        fs::read_to_string(&day_path).with_context(|| format!("reading {}", day_path.display()))?;
+
+    let stub = Regex::new(r"(?m)^fn _part2\(").unwrap();
+    // The registration may carry a `; Metadata { .. }` tail, which is kept as is
+    let register = Regex::new(r"register_day!\(\s*(\d+)\s*,\s*(\d+)\s*,\s*part1\s*([;)])").unwrap();
+    let part2_ident = Regex::new(r"\bpart2\b").unwrap();
+    let stub_ident = Regex::new(r"\b_part2\b").unwrap();
+
+    if part2_ident.is_match(&contents) {
+        bail!("{} already has a `part2`", day_path.display());
+    }
+    if stub.find_iter(&contents).count() != 1 {
+        bail!(
+            "expected exactly one `fn _part2(` in {}",
+            day_path.display()
+        );
+    }
+    let registrations: Vec<_> = register.captures_iter(&contents).collect();
+    let [registration] = registrations.as_slice() else {
+        bail!(
+            "expected exactly one `register_day!({year}, {day}, part1)` in {}",
+            day_path.display()
+        );
+    };
+    if registration[1].parse::<u16>()? != year || registration[2].parse::<u8>()? != day {
+        bail!(
+            "{} registers {} day {}, not {year} day {day}",
+            day_path.display(),
+            &registration[1],
+            &registration[2]
+        );
+    }
+
+    let contents = register.replace(&contents, "register_day!($1, $2, part1, part2$3");
+    let contents = stub_ident.replace_all(&contents, "part2");
+    fs::write(&day_path, contents.as_ref())
+        .with_context(|| format!("writing {}", day_path.display()))?;
+
+    println!("Registered part 2 in {}", day_path.display());
+
+    if manifest::add_placeholder(year, day, 2)? {
+        println!(
+            "Added an unchecked part 2 answer for day{day} to {}; fill it in once known",
+            Manifest::path().display()
+        );
+    }
+
+    Ok(())
+}
+
 fn ensure_year_mod(solutions_dir: &Path, year: u16) -> Result<()> {
     let year_dir = solutions_dir.join(format!("y{year}"));
     let year_mod = year_dir.join("mod.rs");
@@ -159,41 +629,74 @@ fn ensure_solutions_mod(solutions_dir: &Path, year: u16) -> Result<()> {
         if !contents.ends_with('\n') {
             contents.push('\n');
         }
+        contents.push_str(&format!("#[cfg(feature = \"y{year}\")]\n"));
         contents.push_str(&line);
         contents.push('\n');
         fs::write(&solutions_mod, contents)
             .with_context(|| format!("updating {}", solutions_mod.display()))?;
     }
 
+    ensure_year_feature(year)
+}
+
+/// Add a `y<year>` cargo feature (enabled by default) so the new year module gets compiled
+fn ensure_year_feature(year: u16) -> Result<()> {
+    let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+    let contents =
+        fs::read_to_string(&manifest).with_context(|| format!("reading {}", manifest.display()))?;
+
+    let feature = format!("y{year}");
+    if contents
+        .lines()
+        .any(|l| l.trim_start().starts_with(&format!("{feature} =")))
+    {
+        return Ok(());
+    }
+
+    let mut updated = String::new();
+    for line in contents.lines() {
+        match line
+            .strip_prefix("default = [")
+            .and_then(|l| l.strip_suffix(']'))
+        {
+            Some(rest) => {
+                updated.push_str(&format!("default = [{rest}, \"{feature}\"]\n"));
+            }
+            None => {
+                updated.push_str(line);
+                updated.push('\n');
+            }
+        }
+    }
+    updated.push_str(&format!("{feature} = []\n"));
+
+    fs::write(&manifest, updated).with_context(|| format!("updating {}", manifest.display()))?;
+    println!("Added `{feature}` feature to {}", manifest.display());
+
     Ok(())
 }
 
-fn make_day_template(year: u16, day: u8) -> String {
-    format!(
-        "use crate::register_day;\n\
-         \n\
-         fn parse_input(input: &str) -> Vec<String> {{\n\
-             // TODO: parse the input into a more useful structure\n\
-             input\n\
-                 .lines()\n\
-                 .map(|line| line.to_string())\n\
-                 .collect()\n\
-         }}\n\
-         \n\
-         fn part1(input: &str) {{\n\
-             let _data = parse_input(input);\n\
-             // TODO: solve part 1\n\
-             println!(\"Day {day} Part 1: {{}}\", \"TODO\");\n\
-         }}\n\
-         \n\
-         fn _part2(input: &str) {{\n\
-             let _data = parse_input(input);\n\
-             // TODO: solve part 2\n\
-             println!(\"Day {day} Part 2: {{}}\", \"TODO\");\n\
-         }}\n\
-         \n\
-         register_day!({year}, {day}, part1);\n",
-        year = year,
-        day = day
-    )
+/// Render `templates/<template>.rs.tmpl` with the test stub from `templates/tests.rs.tmpl`.
+///
+/// Placeholders: `{{year}}`, `{{day}}`, `{{part2}}` (the part 2 function name),
+/// `{{parts}}` (the registered parts) and `{{tests}}` (the rendered test stub).
+fn make_day_template(opt: &Opt, year: u16, day: u8) -> Result<String> {
+    let templates_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("templates");
+    let read = |name: &str| {
+        let path = templates_dir.join(format!("{name}.rs.tmpl"));
+        fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))
+    };
+
+    let (part2, parts) = if opt.with_part2 {
+        ("part2", "part1, part2")
+    } else {
+        ("_part2", "part1")
+    };
+
+    let template = read(opt.template.file_stem())?.replace("{{tests}}", &read("tests")?);
+    Ok(template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+        .replace("{{part2}}", part2)
+        .replace("{{parts}}", parts))
 }
@@ -0,0 +1,185 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use serde_json::Value;
+
+/// Expectations for one day from the answers manifest
+#[derive(Default)]
+pub struct DayManifest {
+    /// Longest a part may take on the real input, by part
+    pub budgets: BTreeMap<u8, Duration>,
+    /// Expected answers by example file name (without extension) and part. Only the
+    /// listed parts apply to an example; a `null` answer is run but not checked yet.
+    pub examples: BTreeMap<String, BTreeMap<u8, Option<String>>>,
+}
+
+/// `answers.json` at the repository root, keyed by year and then day:
+///
+/// ```json
+/// { "2025": { "11": { "budget_ms": { "2": 50 }, "examples": { "day11_alt": { "2": 2 } } } } }
+/// ```
+#[derive(Default)]
+pub struct Manifest {
+    days: BTreeMap<(u16, u8), DayManifest>,
+}
+
+impl Manifest {
+    pub fn path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answers.json")
+    }
+
+    /// Load the manifest; a missing file is an empty manifest
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(Manifest::default());
+        }
+        let json =
+            fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        let value: Value = serde_json::from_str(&json).context("parsing answers manifest")?;
+        Self::parse(&value).with_context(|| format!("in {}", path.display()))
+    }
+
+    fn parse(value: &Value) -> Result<Self> {
+        let mut days = BTreeMap::new();
+        for (year, year_value) in object(value, "manifest")? {
+            let year: u16 = year
+                .parse()
+                .with_context(|| format!("invalid year {year}"))?;
+            for (day, day_value) in object(year_value, "year")? {
+                let day: u8 = day.parse().with_context(|| format!("invalid day {day}"))?;
+                let mut entry = DayManifest::default();
+                if let Some(budgets) = day_value.get("budget_ms") {
+                    for (part, ms) in object(budgets, "budget_ms")? {
+                        let Some(ms) = ms.as_f64() else {
+                            bail!("{year} day {day}: budget for part {part} is not a number");
+                        };
+                        entry
+                            .budgets
+                            .insert(parse_part(part)?, Duration::from_secs_f64(ms / 1000.0));
+                    }
+                }
+                if let Some(examples) = day_value.get("examples") {
+                    for (name, answers) in object(examples, "examples")? {
+                        let mut parts = BTreeMap::new();
+                        for (part, answer) in object(answers, name)? {
+                            let answer = match answer {
+                                Value::String(answer) => Some(answer.clone()),
+                                Value::Number(answer) => Some(answer.to_string()),
+                                Value::Null => None,
+                                _ => bail!(
+                                    "{year} day {day}: answer for {name} part {part} is not a string, number or null"
+                                ),
+                            };
+                            parts.insert(parse_part(part)?, answer);
+                        }
+                        entry.examples.insert(name.clone(), parts);
+                    }
+                }
+                days.insert((year, day), entry);
+            }
+        }
+        Ok(Manifest { days })
+    }
+
+    pub fn day(&self, year: u16, day: u8) -> Option<&DayManifest> {
+        self.days.get(&(year, day))
+    }
+
+    /// All days in the manifest, ordered by year and day
+    pub fn days(&self) -> impl Iterator<Item = ((u16, u8), &DayManifest)> {
+        self.days.iter().map(|(&key, entry)| (key, entry))
+    }
+}
+
+/// Add a `null` answer for `part` of the day's `day<N>` example unless it already has one,
+/// keeping the rest of `answers.json` as written. A new entry also lists the earlier
+/// parts. Returns whether the file changed.
+pub fn add_placeholder(year: u16, day: u8, part: u8) -> Result<bool> {
+    let path = Manifest::path();
+    let mut value = if path.exists() {
+        let json =
+            fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&json).context("parsing answers manifest")?
+    } else {
+        Value::Object(Default::default())
+    };
+
+    let mut entry = &mut value;
+    for key in [
+        year.to_string(),
+        day.to_string(),
+        "examples".to_string(),
+        format!("day{day}"),
+    ] {
+        let Value::Object(map) = entry else {
+            bail!("{} is not an object in {}", key, path.display());
+        };
+        entry = map
+            .entry(key)
+            .or_insert_with(|| Value::Object(Default::default()));
+    }
+    let Value::Object(answers) = entry else {
+        bail!("day{day} answers are not an object in {}", path.display());
+    };
+    if answers.contains_key(&part.to_string()) {
+        return Ok(false);
+    }
+    // Listing only this part would stop `--examples` running the earlier ones on a new entry
+    if answers.is_empty() {
+        for earlier in 1..part {
+            answers.insert(earlier.to_string(), Value::Null);
+        }
+    }
+    answers.insert(part.to_string(), Value::Null);
+
+    let mut json = String::new();
+    render(&value, 0, &mut json);
+    json.push('\n');
+    fs::write(&path, json).with_context(|| format!("writing {}", path.display()))?;
+    Ok(true)
+}
+
+/// Pretty-print in the manifest's layout: objects of plain values on one line, anything
+/// else one key per line
+fn render(value: &Value, indent: usize, out: &mut String) {
+    let Value::Object(map) = value else {
+        out.push_str(&value.to_string());
+        return;
+    };
+    if map.is_empty() {
+        out.push_str("{}");
+    } else if map.values().all(|v| !v.is_object() && !v.is_array()) {
+        let fields: Vec<String> = map
+            .iter()
+            .map(|(key, value)| format!("{}: {value}", Value::from(key.as_str())))
+            .collect();
+        out.push_str(&format!("{{ {} }}", fields.join(", ")));
+    } else {
+        out.push_str("{\n");
+        for (i, (key, value)) in map.iter().enumerate() {
+            let key = Value::from(key.as_str());
+            out.push_str(&format!("{}{key}: ", " ".repeat(indent + 2)));
+            render(value, indent + 2, out);
+            out.push_str(if i + 1 < map.len() { ",\n" } else { "\n" });
+        }
+        out.push_str(&format!("{}}}", " ".repeat(indent)));
+    }
+}
+
+fn object<'a>(value: &'a Value, what: &str) -> Result<&'a serde_json::Map<String, Value>> {
+    value
+        .as_object()
+        .with_context(|| format!("{what} is not an object"))
+}
+
+fn parse_part(part: &str) -> Result<u8> {
+    match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => bail!("invalid part {part}"),
+    }
+}
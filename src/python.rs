@@ -0,0 +1,156 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result, bail};
+use regex::Regex;
+
+use crate::watch::parse_answers;
+use crate::{Answer, Opt, encryption, make_path};
+
+/// Interpreter used when neither `--python` nor `AOC_PYTHON` is set
+const DEFAULT_INTERPRETER: &str = "python3";
+
+/// A `dayN.py` prototype living next to the Rust solution for the same day
+pub struct Script {
+    pub year: u16,
+    pub day: u8,
+    pub path: PathBuf,
+    /// Parts the script prints an answer for, read from its `Day N Part K:` format strings
+    pub parts: Vec<u8>,
+}
+
+impl Script {
+    fn load(year: u16, day: u8, path: PathBuf) -> Result<Self> {
+        let source =
+            fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        let re = Regex::new(r"Part (\d):").unwrap();
+        let mut parts: Vec<u8> = re
+            .captures_iter(&source)
+            .filter_map(|c| c[1].parse().ok())
+            .collect();
+        parts.sort_unstable();
+        parts.dedup();
+
+        Ok(Script {
+            year,
+            day,
+            path,
+            parts,
+        })
+    }
+
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
+
+fn solutions_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src/solutions")
+}
+
+/// The Python prototype for a day, if there is one
+pub fn find_script(year: u16, day: u8) -> Option<Script> {
+    let path = solutions_dir()
+        .join(format!("y{year}"))
+        .join(format!("day{day}.py"));
+    path.exists()
+        .then(|| Script::load(year, day, path).ok())
+        .flatten()
+}
+
+/// Every Python prototype under `src/solutions`, ordered by year and day
+pub fn list_scripts() -> Vec<Script> {
+    let re = Regex::new(r"^y(\d{4})/day(\d+)\.py$").unwrap();
+    let Ok(years) = fs::read_dir(solutions_dir()) else {
+        return Vec::new();
+    };
+
+    let mut scripts: Vec<Script> = years
+        .flatten()
+        .filter_map(|year| fs::read_dir(year.path()).ok())
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let relative = path.strip_prefix(solutions_dir()).ok()?;
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            let c = re.captures(&relative)?;
+            Script::load(c[1].parse().ok()?, c[2].parse().ok()?, path).ok()
+        })
+        .collect();
+    scripts.sort_by_key(|s| (s.year, s.day));
+    scripts
+}
+
+/// Interpreter to run prototypes with: `--python`, then `AOC_PYTHON`, then `python3`.
+/// A blank value (like the `.env.example` placeholder) counts as unset
+pub fn interpreter(opt: &Opt) -> String {
+    opt.python
+        .clone()
+        .or_else(|| std::env::var("AOC_PYTHON").ok())
+        .filter(|interpreter| !interpreter.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_INTERPRETER.to_string())
+}
+
+/// Run a prototype on the selected input and return the answers it printed.
+///
+/// Scripts take the input path as their only argument. Encrypted and embedded inputs
+/// have no plain file to point at, so they are written to a temporary file first.
+pub fn run_script(
+    opt: &Opt,
+    script: &Script,
+    input: &str,
+) -> Result<(Vec<(u8, Answer)>, Duration)> {
+    let path = make_path(script.year, script.day, opt);
+    let plain =
+        fs::read_to_string(&path).is_ok_and(|contents| !encryption::is_encrypted(&contents));
+    let temp = (!plain).then(|| {
+        std::env::temp_dir().join(format!(
+            "aoc-y{}-day{}-{}.txt",
+            script.year,
+            script.day,
+            std::process::id()
+        ))
+    });
+    if let Some(temp) = &temp {
+        fs::write(temp, input).with_context(|| format!("writing {}", temp.display()))?;
+    }
+
+    let interpreter = interpreter(opt);
+    let start = Instant::now();
+    let output = Command::new(&interpreter)
+        .arg(&script.path)
+        .arg(temp.as_ref().unwrap_or(&path))
+        .output();
+    let duration = start.elapsed();
+    if let Some(temp) = &temp {
+        let _ = fs::remove_file(temp);
+    }
+
+    let output = output.with_context(|| format!("running `{interpreter}`"))?;
+    if !output.status.success() {
+        bail!(
+            "{} failed: {}",
+            script.file_name(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let answers: Vec<(u8, Answer)> = parse_answers(&stdout)
+        .into_iter()
+        .filter_map(|(part, answer)| {
+            let part = part.strip_prefix("Part ")?.parse().ok()?;
+            Some((part, Answer(answer)))
+        })
+        .collect();
+    if answers.is_empty() {
+        bail!("no answer in output of {}:\n{stdout}", script.file_name());
+    }
+    Ok((answers, duration))
+}
@@ -0,0 +1,237 @@
+use std::io::{self, BufRead, Write};
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use hashbrown::HashMap;
+
+use crate::inspect::{inspect_input, render_report};
+use crate::stress::catch_panic;
+use crate::{
+    DEFAULT_VARIANT, Opt, SolutionFn, get_input_for_day, get_solution, runner, time_solution,
+};
+
+const HELP: &str = "\
+Commands:
+  run <part> [alt=<name>] [variant=<name>]   run a part (alt/variant apply to this run only)
+  bench <part> [runs] [alt=..] [variant=..]  time a part over several runs (default 10)
+  inspect [alt=<name>]                       describe the current input
+  reload input                               re-read the current input from disk
+  switch real | example | alt=<name>         change the default input
+  switch day <day> | year <year>             change the puzzle
+  help                                       show this message
+  quit                                       leave";
+
+/// Which input a command runs on
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct InputKey {
+    year: u16,
+    day: u8,
+    real: bool,
+    alt: Option<String>,
+}
+
+/// Interactive session for one day, keeping inputs in memory between commands
+pub struct Repl {
+    opt: Opt,
+    year: u16,
+    day: u8,
+    inputs: HashMap<InputKey, String>,
+}
+
+impl Repl {
+    pub fn new(opt: &Opt, year: u16, day: u8) -> Self {
+        Repl {
+            opt: opt.clone(),
+            year,
+            day,
+            inputs: HashMap::new(),
+        }
+    }
+
+    /// Read commands from stdin until `quit` or end of input
+    pub fn run(&mut self) -> Result<()> {
+        println!("Type `help` for commands");
+        let mut lines = io::stdin().lock().lines();
+
+        loop {
+            print!("{}> ", self.prompt());
+            io::stdout().flush()?;
+
+            let Some(line) = lines.next().transpose()? else {
+                println!();
+                return Ok(());
+            };
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words.as_slice() {
+                [] => {}
+                ["quit" | "exit" | "q"] => return Ok(()),
+                ["help" | "?"] => println!("{HELP}"),
+                [command, args @ ..] => {
+                    if let Err(e) = self.execute(command, args) {
+                        eprintln!("{e}");
+                    }
+                }
+            }
+        }
+    }
+
+    fn prompt(&self) -> String {
+        let input = match (&self.opt.alt, self.opt.real) {
+            (Some(alt), _) => alt.as_str(),
+            (None, true) => "real",
+            (None, false) => "example",
+        };
+        format!("{} day {} ({input})", self.year, self.day)
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<()> {
+        let (positional, options) = split_args(args)?;
+
+        match (command, positional.as_slice()) {
+            ("run", [part]) => {
+                let part = parse_part(part)?;
+                let f = self.solution(part, &options)?;
+                let input = self.input(options.get("alt").copied())?;
+                catch_panic(|| runner(f, &input, self.day, part))
+                    .map_err(|e| anyhow!("Part {part} panicked: {e}"))?;
+            }
+            ("bench", [part, runs @ ..]) => {
+                let part = parse_part(part)?;
+                let runs = match runs {
+                    [] => 10,
+                    [runs] => runs.parse().context("runs must be a number")?,
+                    _ => bail!("usage: bench <part> [runs]"),
+                };
+                let f = self.solution(part, &options)?;
+                let input = self.input(options.get("alt").copied())?;
+                catch_panic(|| bench(f, &input, runs))
+                    .map_err(|e| anyhow!("Part {part} panicked: {e}"))?;
+            }
+            ("inspect", []) => {
+                let input = self.input(options.get("alt").copied())?;
+                println!("{}", render_report(&inspect_input(&input)));
+            }
+            ("reload", [] | ["input"]) => {
+                let key = self.key(options.get("alt").copied());
+                self.inputs.remove(&key);
+                self.input(key.alt.as_deref())?;
+                println!("Reloaded input");
+            }
+            ("switch", ["real"]) => (self.opt.real, self.opt.alt) = (true, None),
+            ("switch", ["example"]) => (self.opt.real, self.opt.alt) = (false, None),
+            ("switch", []) if options.contains_key("alt") => {
+                // `make_path` ignores the alt name for real inputs
+                (self.opt.real, self.opt.alt) = (false, Some(options["alt"].to_string()));
+            }
+            // Alternative inputs are named per day, so switching puzzles drops them
+            ("switch", ["day", day]) => {
+                self.day = day.parse().context("day must be a number")?;
+                self.opt.alt = None;
+            }
+            ("switch", ["year", year]) => {
+                let year: u16 = year.parse().context("year must be a number")?;
+                self.year = if year < 100 { 2000 + year } else { year };
+                self.opt.alt = None;
+            }
+            _ => bail!(
+                "Unknown command `{}` (type `help` for commands)",
+                args_line(command, args)
+            ),
+        }
+
+        Ok(())
+    }
+
+    fn solution(&self, part: u8, options: &HashMap<&str, &str>) -> Result<SolutionFn> {
+        let Some(solution) = get_solution(self.year, self.day) else {
+            bail!("No solution found for {} day {}", self.year, self.day);
+        };
+        let variant = options.get("variant").copied().unwrap_or(DEFAULT_VARIANT);
+        solution
+            .variant(part, variant)
+            .with_context(|| format!("No variant `{variant}` for part {part}"))
+    }
+
+    fn key(&self, alt: Option<&str>) -> InputKey {
+        InputKey {
+            year: self.year,
+            day: self.day,
+            real: self.opt.real && alt.is_none(),
+            alt: alt.map(str::to_string).or_else(|| self.opt.alt.clone()),
+        }
+    }
+
+    /// The selected input, read on first use and cached afterwards
+    fn input(&mut self, alt: Option<&str>) -> Result<String> {
+        let key = self.key(alt);
+        if let Some(input) = self.inputs.get(&key) {
+            return Ok(input.clone());
+        }
+
+        let mut opt = self.opt.clone();
+        opt.real = key.real;
+        opt.alt = key.alt.clone();
+        let input = get_input_for_day(&opt, key.year, key.day)?;
+        self.inputs.insert(key, input.clone());
+        Ok(input)
+    }
+}
+
+/// Separate `key=value` options from positional arguments
+fn split_args<'a>(args: &[&'a str]) -> Result<(Vec<&'a str>, HashMap<&'a str, &'a str>)> {
+    let mut positional = Vec::new();
+    let mut options = HashMap::new();
+    for arg in args {
+        match arg.split_once('=') {
+            Some((key @ ("alt" | "variant"), value)) => {
+                options.insert(key, value);
+            }
+            Some((key, _)) => bail!("Unknown option `{key}` (expected alt or variant)"),
+            None => positional.push(*arg),
+        }
+    }
+    Ok((positional, options))
+}
+
+fn parse_part(part: &str) -> Result<u8> {
+    match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => bail!("part must be 1 or 2"),
+    }
+}
+
+fn args_line(command: &str, args: &[&str]) -> String {
+    std::iter::once(command)
+        .chain(args.iter().copied())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn bench(f: SolutionFn, input: &str, runs: usize) {
+    let mut times: Vec<Duration> = Vec::with_capacity(runs);
+    let mut answers = Vec::new();
+    for _ in 0..runs.max(1) {
+        let (answer, duration) = time_solution(f, input);
+        times.push(duration);
+        if !answers.contains(&answer) {
+            answers.push(answer);
+        }
+    }
+    times.sort_unstable();
+
+    let mean = times.iter().sum::<Duration>() / times.len() as u32;
+    let answers = answers
+        .iter()
+        .map(|a| a.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    println!("Answer: {answers}");
+    println!(
+        "{} runs: min {:?}, median {:?}, mean {mean:?}, max {:?}",
+        times.len(),
+        times[0],
+        times[times.len() / 2],
+        times[times.len() - 1]
+    );
+}
@@ -0,0 +1,137 @@
+#[cfg(feature = "serve")]
+use std::panic::{AssertUnwindSafe, catch_unwind};
+#[cfg(feature = "serve")]
+use std::str::FromStr;
+#[cfg(feature = "serve")]
+use std::time::Instant;
+
+use anyhow::Result;
+#[cfg(feature = "serve")]
+use serde_json::{Value, json};
+#[cfg(feature = "serve")]
+use tiny_http::{Header, Method, Request, Response, Server};
+
+#[cfg(feature = "serve")]
+use crate::{DEFAULT_VARIANT, get_solution, list_solutions, time_solution, timing};
+
+#[cfg(not(feature = "serve"))]
+pub fn serve(_addr: &str) -> Result<()> {
+    anyhow::bail!("Built without the `serve` feature")
+}
+
+/// Serve the solution registry over HTTP until interrupted:
+///
+/// - `GET /solutions` lists registered days
+/// - `POST /run/{year}/{day}/{part}[?variant=<name>]` runs a part on the request body and
+///   returns the answer and timing as JSON
+#[cfg(feature = "serve")]
+pub fn serve(addr: &str) -> Result<()> {
+    let server = Server::http(addr).map_err(|e| anyhow::anyhow!("binding {addr}: {e}"))?;
+    println!("Listening on http://{}", server.server_addr());
+
+    for mut request in server.incoming_requests() {
+        let (status, body) = match handle(&mut request) {
+            Ok(body) => (200, body),
+            Err((status, message)) => (status, json!({ "error": message })),
+        };
+        println!("{} {} -> {status}", request.method(), request.url());
+
+        let response = Response::from_string(body.to_string())
+            .with_status_code(status)
+            .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+        if let Err(e) = request.respond(response) {
+            eprintln!("Failed to respond: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "serve")]
+type HandlerResult = std::result::Result<Value, (u16, String)>;
+
+#[cfg(feature = "serve")]
+fn handle(request: &mut Request) -> HandlerResult {
+    let url = request.url().to_string();
+    let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    match (request.method(), segments.as_slice()) {
+        (Method::Get, ["solutions"]) => Ok(solutions_json()),
+        (Method::Post, ["run", year, day, part]) => {
+            let variant = query
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("variant="))
+                .unwrap_or(DEFAULT_VARIANT);
+            let (year, day, part) = (
+                parse_segment(year, "year")?,
+                parse_segment(day, "day")?,
+                parse_segment(part, "part")?,
+            );
+            run_json(year, day, part, variant, request)
+        }
+        (_, ["solutions"] | ["run", ..]) => Err((405, "method not allowed".to_string())),
+        _ => Err((404, format!("no route for {path}"))),
+    }
+}
+
+#[cfg(feature = "serve")]
+fn parse_segment<T: FromStr>(segment: &str, what: &str) -> std::result::Result<T, (u16, String)> {
+    segment
+        .parse()
+        .map_err(|_| (400, format!("invalid {what}: {segment}")))
+}
+
+#[cfg(feature = "serve")]
+fn solutions_json() -> Value {
+    list_solutions()
+        .into_iter()
+        .map(|(year, day)| {
+            let solution = get_solution(year, day).unwrap();
+            let parts: Vec<u8> = [1, 2]
+                .into_iter()
+                .filter(|&p| solution.part(p).is_some())
+                .collect();
+            let meta = solution.meta.unwrap_or(crate::Metadata::EMPTY);
+            json!({
+                "year": year,
+                "day": day,
+                "parts": parts,
+                "title": meta.title,
+                "tags": meta.tags.iter().map(|t| t.as_str()).collect::<Vec<_>>(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "serve")]
+fn run_json(year: u16, day: u8, part: u8, variant: &str, request: &mut Request) -> HandlerResult {
+    let solution =
+        get_solution(year, day).ok_or((404, format!("no solution for {year} day {day}")))?;
+    let f = solution.variant(part, variant).ok_or((
+        404,
+        format!("no variant `{variant}` for {year} day {day} part {part}"),
+    ))?;
+
+    let mut input = String::new();
+    request
+        .as_reader()
+        .read_to_string(&mut input)
+        .map_err(|e| (400, format!("reading input: {e}")))?;
+    let input = input.replace("\r\n", "\n");
+
+    timing::reset();
+    let (answer, duration) = catch_unwind(AssertUnwindSafe(|| time_solution(f, &input)))
+        .map_err(|_| (500, "solution panicked".to_string()))?;
+    let phases = timing::phases_json(&timing::take_phases(Instant::now()));
+
+    Ok(json!({
+        "year": year,
+        "day": day,
+        "part": part,
+        "variant": variant,
+        "answer": answer.to_string(),
+        "duration_ns": duration.as_nanos() as u64,
+        "phases": phases,
+    }))
+}
@@ -1,6 +1,11 @@
 // Import all year modules - this triggers their registration
+#[cfg(feature = "y2021")]
 pub mod y2021;
+#[cfg(feature = "y2022")]
 pub mod y2022;
+#[cfg(feature = "y2023")]
 pub mod y2023;
+#[cfg(feature = "y2024")]
 pub mod y2024;
+#[cfg(feature = "y2025")]
 pub mod y2025;
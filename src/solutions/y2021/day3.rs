@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, register_day};
 
 fn parse_input(input: &str) -> Vec<Vec<u32>> {
     input
@@ -24,14 +24,10 @@ fn count_bits(input: &[Vec<u32>]) -> Vec<(u32, u32)> {
 }
 
 fn most_common(counts: &(u32, u32)) -> u32 {
-    if counts.1 >= counts.0 {
-        1
-    } else {
-        0
-    }
+    if counts.1 >= counts.0 { 1 } else { 0 }
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> u32 {
     let data = parse_input(input);
     let inner_len = data[0].len();
     let mask: u32 = (1 << inner_len) - 1;
@@ -42,8 +38,7 @@ fn part1(input: &str) {
         .fold(0, |acc, bit| acc << 1 | bit);
 
     let epsilon = gamma ^ mask;
-    let result = gamma * epsilon;
-    println!("Day 3 Part 1: {result}");
+    gamma * epsilon
 }
 
 fn determine_rating(input: &[Vec<u32>], mask_fn: fn(&[Vec<u32>]) -> Vec<u32>) -> u32 {
@@ -62,7 +57,7 @@ fn determine_rating(input: &[Vec<u32>], mask_fn: fn(&[Vec<u32>]) -> Vec<u32>) ->
     vec[0].iter().fold(0, |acc, &bit| acc << 1 | bit)
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> u32 {
     let data = parse_input(input);
 
     let ox_rating = determine_rating(&data, |mask_in: &[Vec<u32>]| {
@@ -76,8 +71,11 @@ fn part2(input: &str) {
             .collect()
     });
 
-    let result = ox_rating * co_rating;
-    println!("Day 3 Part 2: {result}");
+    ox_rating * co_rating
 }
 
-register_day!(2021, 3, part1, part2);
+register_day!(2021, 3, part1, part2; Metadata {
+    title: "Binary Diagnostic",
+    techniques: &["bit counting"],
+    ..Metadata::EMPTY
+});
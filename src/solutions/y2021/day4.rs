@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use nalgebra::Matrix5;
 
 type Board = Matrix5<u32>;
@@ -48,21 +48,22 @@ fn get_unmarked(board: &Board, nums: &[u32]) -> Vec<u32> {
         .collect()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> u32 {
     let (numbers, boards) = parse_input(input);
 
     for (i, val) in numbers.iter().enumerate() {
         for board in boards.iter() {
             if check(board, &numbers[0..=i]) {
                 let result = get_unmarked(board, &numbers[0..=i]).iter().sum::<u32>() * val;
-                println!("Day 4 Part 1: {result}");
-                return;
+                return result;
             }
         }
     }
+
+    panic!("No board wins");
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> u32 {
     let (numbers, mut boards) = parse_input(input);
 
     for (i, val) in numbers.iter().enumerate() {
@@ -72,20 +73,28 @@ fn part2(input: &str) {
                 .filter(|board| check(board, &numbers[0..=i]))
                 .collect();
             if winners.len() == boards.len() {
-                let result = get_unmarked(winners[0], &numbers[0..=i]).iter().sum::<u32>() * val;
-                println!("Day 4 Part 2: {result}");
-                return;
+                let result = get_unmarked(winners[0], &numbers[0..=i])
+                    .iter()
+                    .sum::<u32>()
+                    * val;
+                return result;
             }
             boards.retain(|board| !check(board, &numbers[0..=i]));
         } else {
             let board = &boards[0];
             if check(board, &numbers[0..=i]) {
                 let result = get_unmarked(board, &numbers[0..=i]).iter().sum::<u32>() * val;
-                println!("Day 4 Part 2: {result}");
-                return;
+                return result;
             }
         }
     }
+
+    panic!("No board wins last");
 }
 
-register_day!(2021, 4, part1, part2);
+register_day!(2021, 4, part1, part2; Metadata {
+    title: "Giant Squid",
+    tags: &[Tag::Grid, Tag::Simulation],
+    techniques: &["nalgebra Matrix5"],
+    ..Metadata::EMPTY
+});
@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 
 fn parse_input(input: &str) -> Vec<u32> {
     let mut cal: u32 = 0;
@@ -19,17 +19,21 @@ fn parse_input(input: &str) -> Vec<u32> {
     cal_vec
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> u32 {
     let cal_vec = parse_input(input);
     let result = cal_vec.iter().max().unwrap();
-    println!("Day 1 Part 1: {result}");
+    *result
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> u32 {
     let mut cal_vec = parse_input(input);
     cal_vec.sort();
     let result: u32 = cal_vec.iter().rev().take(3).sum();
-    println!("Day 1 Part 2: {result}");
+    result
 }
 
-register_day!(2022, 1, part1, part2);
+register_day!(2022, 1, part1, part2; Metadata {
+    title: "Calorie Counting",
+    tags: &[Tag::Parsing],
+    ..Metadata::EMPTY
+});
@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use itertools::Itertools;
 use serde_json::Value;
 use std::cmp::Ordering;
@@ -37,22 +37,20 @@ fn compare(a: &Value, b: &Value) -> Ordering {
     }
 }
 
-fn part1(input: &str) {
-    let res = parse_input(input)
+fn part1(input: &str) -> usize {
+    parse_input(input)
         .iter()
         .positions(|(left, right)| compare(left, right).is_lt())
         .map(|i| i + 1)
-        .sum::<usize>();
-
-    println!("Day 13 Part 1: {res}");
+        .sum::<usize>()
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> usize {
     let markers = [
         serde_json::from_str::<Value>("[[2]]").unwrap(),
         serde_json::from_str::<Value>("[[6]]").unwrap(),
     ];
-    let res = parse_input(input)
+    parse_input(input)
         .iter()
         .flat_map(|(a, b)| [a, b])
         .chain(&markers)
@@ -60,9 +58,12 @@ fn part2(input: &str) {
         .sorted_by(compare)
         .positions(|packet| markers.contains(&packet))
         .map(|i| i + 1)
-        .product::<usize>();
-
-    println!("Day 13 Part 2: {res}");
+        .product::<usize>()
 }
 
-register_day!(2022, 13, part1, part2);
+register_day!(2022, 13, part1, part2; Metadata {
+    title: "Distress Signal",
+    tags: &[Tag::Parsing],
+    techniques: &["serde_json", "custom Ordering"],
+    ..Metadata::EMPTY
+});
@@ -1,4 +1,6 @@
-use crate::register_day;
+use crate::utils::Loc;
+use crate::visualize::{self, Color, Frame};
+use crate::{Metadata, Tag, register_day};
 use nalgebra::{DMatrix, Dyn, Matrix, VecStorage};
 
 type Path = Vec<(usize, usize)>;
@@ -87,26 +89,38 @@ fn fall_sand(cavern: &mut Cavern, x_offset: usize) -> Result<(usize, usize), ()>
     }
 }
 
-fn part1(input: &str) {
+fn cavern_frame(cavern: &Cavern, sand: (usize, usize)) -> Frame {
+    Frame::from_fn(cavern.nrows(), cavern.ncols(), |r, c| cavern[(r, c)])
+        .highlight([Loc::from((sand.1, sand.0))], Color::Yellow)
+}
+
+fn part1(input: &str) -> i32 {
     let (mut cavern, x_offset) = parse_input(input, false);
 
     let mut sand_count = 0;
-    while fall_sand(&mut cavern, x_offset).is_ok() {
+    while let Ok(sand) = fall_sand(&mut cavern, x_offset) {
         sand_count += 1;
+        visualize::animate(|| cavern_frame(&cavern, sand));
     }
 
-    println!("Day 14 Part 1: {sand_count}");
+    sand_count
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i32 {
     let (mut cavern, x_offset) = parse_input(input, true);
 
     let mut sand_count = 0;
-    while fall_sand(&mut cavern, x_offset).is_ok() {
+    while let Ok(sand) = fall_sand(&mut cavern, x_offset) {
         sand_count += 1;
+        visualize::animate(|| cavern_frame(&cavern, sand));
     }
 
-    println!("Day 14 Part 2: {}", sand_count + 1);
+    sand_count + 1
 }
 
-register_day!(2022, 14, part1, part2);
+register_day!(2022, 14, part1, part2; Metadata {
+    title: "Regolith Reservoir",
+    tags: &[Tag::Grid, Tag::Simulation],
+    techniques: &["nalgebra DMatrix", "visualize"],
+    ..Metadata::EMPTY
+});
@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, info, register_day};
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 use std::{
@@ -120,7 +120,7 @@ fn determine_ranges(
         .collect()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> i64 {
     // Detect if using example (small numbers) or real input
     let (sensors, beacons) = parse_input(input);
     let target_row = if sensors.len() <= 14 { 10 } else { 2_000_000 };
@@ -135,10 +135,10 @@ fn part1(input: &str) {
 
     res -= beacons.iter().filter(|&&b| b.y == target_row).count() as i64;
 
-    println!("Day 15 Part 1: {res}");
+    res
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i64 {
     let (sensors, _) = parse_input(input);
     let (t_min, t_max) = (0, if sensors.len() <= 14 { 20 } else { 4_000_000 });
 
@@ -165,7 +165,14 @@ fn part2(input: &str) {
 
     let res = x * 4_000_000 + y;
 
-    println!("Day 15 Part 2: {res} (x={x}, y={y})");
+    info!("x={x}, y={y}");
+
+    res
 }
 
-register_day!(2022, 15, part1, part2);
+register_day!(2022, 15, part1, part2; Metadata {
+    title: "Beacon Exclusion Zone",
+    tags: &[Tag::Geometry],
+    techniques: &["manhattan distance", "range merging"],
+    notes: "Detects the example by its sensor count to pick the target row and search area",
+});
@@ -1,7 +1,11 @@
-use crate::register_day;
+use crate::utils::Loc;
+use crate::visualize::{self, Color, Frame};
+use crate::{Metadata, Tag, register_day};
 use std::collections::VecDeque;
 
 const WIDTH: usize = 7;
+/// Number of rows from the top of the board shown when visualizing
+const VISIBLE_ROWS: usize = 30;
 const SHAPE_ORDER: [ShapeType; 5] = [
     ShapeType::Horizontal,
     ShapeType::Cross,
@@ -144,7 +148,17 @@ fn parse_input(input: &str) -> Vec<MoveDirection> {
         .collect()
 }
 
-fn part1(input: &str) {
+fn board_frame(board: &Board, shape: &Shape) -> Frame {
+    Frame::from_fn(board.len().min(VISIBLE_ROWS), WIDTH, |r, c| {
+        if board[r][c] { '#' } else { '.' }
+    })
+    .highlight(
+        shape.positions.iter().map(|&(x, y)| Loc::from((y, x))),
+        Color::Green,
+    )
+}
+
+fn part1(input: &str) -> usize {
     let jet_stream = parse_input(input);
     let mut board: Board = vec![vec![false; WIDTH]; 0].into();
 
@@ -177,10 +191,17 @@ fn part1(input: &str) {
                 }
             }
         } {}
+
+        visualize::animate(|| board_frame(&board, &shape));
     }
     board.trim_space();
 
-    println!("Day 17 Part 1: {}", board.len());
+    board.len()
 }
 
-register_day!(2022, 17, part1);
+register_day!(2022, 17, part1; Metadata {
+    title: "Pyroclastic Flow",
+    tags: &[Tag::Grid, Tag::Simulation],
+    techniques: &["visualize"],
+    ..Metadata::EMPTY
+});
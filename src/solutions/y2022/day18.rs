@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use hashbrown::HashSet;
 use itertools::Itertools;
 
@@ -29,19 +29,17 @@ fn parse_input(input: &str) -> HashSet<Loc> {
         .collect()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let drops = parse_input(input);
 
-    let surface_area = drops
+    drops
         .iter()
         .flat_map(|&p| sides(p))
         .filter(|s| !drops.contains(s))
-        .count();
-
-    println!("Day 18 Part 1: {surface_area}");
+        .count()
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> usize {
     let drops = parse_input(input);
     let max = *drops.iter().flat_map(|(x, y, z)| [x, y, z]).max().unwrap();
 
@@ -60,13 +58,16 @@ fn part2(input: &str) {
         stack.extend(new_locs.iter());
     }
 
-    let surface_area = drops
+    drops
         .iter()
         .flat_map(|&p| sides(p))
         .filter(|s| steam.contains(s))
-        .count();
-
-    println!("Day 18 Part 2: {surface_area}");
+        .count()
 }
 
-register_day!(2022, 18, part1, part2);
+register_day!(2022, 18, part1, part2; Metadata {
+    title: "Boiling Boulders",
+    tags: &[Tag::Geometry, Tag::Graph],
+    techniques: &["flood fill"],
+    ..Metadata::EMPTY
+});
@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 enum RPS {
@@ -41,7 +41,7 @@ fn parse_input(input: &str) -> Vec<(char, char)> {
         .collect()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> u32 {
     let score: u32 = parse_input(input)
         .iter()
         .map(|&(opponent, player)| (opponent.into(), player.into()))
@@ -53,10 +53,10 @@ fn part1(input: &str) {
             } + beat(opponent, player)
         });
 
-    println!("Day 2 Part 1: {score}");
+    score
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> u32 {
     let score: u32 = parse_input(input)
         .iter()
         .map(|&(opponent, result)| (opponent.into(), result))
@@ -83,7 +83,11 @@ fn part2(input: &str) {
             } + beat(opponent, player)
         });
 
-    println!("Day 2 Part 2: {score}");
+    score
 }
 
-register_day!(2022, 2, part1, part2);
+register_day!(2022, 2, part1, part2; Metadata {
+    title: "Rock Paper Scissors",
+    tags: &[Tag::Parsing],
+    ..Metadata::EMPTY
+});
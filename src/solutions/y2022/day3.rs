@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use hashbrown::HashSet;
 use itertools::Itertools;
 
@@ -13,7 +13,7 @@ fn parse_input(input: &str) -> Vec<Vec<u32>> {
         .collect()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> u32 {
     let res: u32 = parse_input(input)
         .iter()
         .map(|v| v.split_at(v.len() / 2))
@@ -25,10 +25,10 @@ fn part1(input: &str) {
         })
         .fold(0, |acc, (x, y)| acc + x.intersection(&y).sum::<u32>());
 
-    println!("Day 3 Part 1: {res}");
+    res
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> u32 {
     let res: u32 = parse_input(input)
         .iter()
         .map(|v| HashSet::from_iter(v.iter().cloned()))
@@ -42,7 +42,12 @@ fn part2(input: &str) {
                 .sum::<u32>()
         });
 
-    println!("Day 3 Part 2: {res}");
+    res
 }
 
-register_day!(2022, 3, part1, part2);
+register_day!(2022, 3, part1, part2; Metadata {
+    title: "Rucksack Reorganization",
+    tags: &[Tag::Parsing],
+    techniques: &["set intersection"],
+    ..Metadata::EMPTY
+});
@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use std::cmp::Ordering;
 
 fn parse_input(input: &str) -> Vec<(u32, u32, u32, u32)> {
@@ -18,8 +18,8 @@ fn parse_input(input: &str) -> Vec<(u32, u32, u32, u32)> {
         .collect()
 }
 
-fn part1(input: &str) {
-    let sum = parse_input(input)
+fn part1(input: &str) -> usize {
+    parse_input(input)
         .iter()
         .map(|(r1, f1, r2, f2)| (r1.cmp(r2), f1.cmp(f2)))
         .filter(|(x, y)| {
@@ -29,13 +29,11 @@ fn part1(input: &str) {
                     (Ordering::Less, Ordering::Greater) | (Ordering::Greater, Ordering::Less)
                 )
         })
-        .count();
-
-    println!("Day 4 Part 1: {sum}");
+        .count()
 }
 
-fn part2(input: &str) {
-    let sum = parse_input(input)
+fn part2(input: &str) -> usize {
+    parse_input(input)
         .iter()
         .map(|(r1, f1, r2, f2)| (r1.cmp(r2), f1.cmp(f2), r1.cmp(f2), f1.cmp(r2)))
         .filter(|(a, b, c, d)| {
@@ -48,9 +46,12 @@ fn part2(input: &str) {
                         | (Ordering::Greater, _, Ordering::Less, _) // range 1 start is in range 2
                 )
         })
-        .count();
-
-    println!("Day 4 Part 2: {sum}");
+        .count()
 }
 
-register_day!(2022, 4, part1, part2);
+register_day!(2022, 4, part1, part2; Metadata {
+    title: "Camp Cleanup",
+    tags: &[Tag::Geometry],
+    techniques: &["range overlap"],
+    ..Metadata::EMPTY
+});
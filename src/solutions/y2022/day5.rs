@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use itertools::Itertools;
 use std::collections::HashMap;
 
@@ -39,7 +39,7 @@ fn parse_input(input: &str) -> (Stack, Moves) {
     (stacks, moves)
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> String {
     let (mut stacks, moves) = parse_input(input);
 
     moves.iter().for_each(|(qty, src, dst)| {
@@ -53,10 +53,10 @@ fn part1(input: &str) {
     for i in 0..stacks.len() {
         result.push(*stacks[&i].last().unwrap());
     }
-    println!("Day 5 Part 1: {result}");
+    result
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> String {
     let (mut stacks, moves) = parse_input(input);
 
     moves.iter().for_each(|(qty, src, dst)| {
@@ -73,7 +73,12 @@ fn part2(input: &str) {
     for i in 0..stacks.len() {
         result.push(*stacks[&i].last().unwrap());
     }
-    println!("Day 5 Part 2: {result}");
+    result
 }
 
-register_day!(2022, 5, part1, part2);
+register_day!(2022, 5, part1, part2; Metadata {
+    title: "Supply Stacks",
+    tags: &[Tag::Parsing, Tag::Simulation],
+    techniques: &["into_group_map"],
+    ..Metadata::EMPTY
+});
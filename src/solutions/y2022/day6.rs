@@ -1,32 +1,38 @@
-use crate::register_day;
+use crate::{Metadata, register_day};
 use itertools::Itertools;
 
 fn parse_input(input: &str) -> Vec<char> {
     input.chars().collect()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let chars = parse_input(input);
 
     for i in 0..chars.len() - 3 {
         let slice = &chars[i..i + 4];
         if slice.iter().unique().count() == 4 {
-            println!("Day 6 Part 1: {}", i + 4);
-            return;
+            return i + 4;
         }
     }
+
+    panic!("No marker found");
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> usize {
     let chars = parse_input(input);
 
     for i in 0..chars.len() - 13 {
         let slice = &chars[i..i + 14];
         if slice.iter().unique().count() == 14 {
-            println!("Day 6 Part 2: {}", i + 14);
-            return;
+            return i + 14;
         }
     }
+
+    panic!("No marker found");
 }
 
-register_day!(2022, 6, part1, part2);
+register_day!(2022, 6, part1, part2; Metadata {
+    title: "Tuning Trouble",
+    techniques: &["sliding window"],
+    ..Metadata::EMPTY
+});
@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 
 fn parse_input(input: &str, replace: bool) -> u32 {
     input
@@ -29,12 +29,16 @@ fn parse_input(input: &str, replace: bool) -> u32 {
         .sum()
 }
 
-fn part1(input: &str) {
-    println!("Day 1 Part 1: {}", parse_input(input, false));
+fn part1(input: &str) -> u32 {
+    parse_input(input, false)
 }
 
-fn part2(input: &str) {
-    println!("Day 1 Part 2: {}", parse_input(input, true));
+fn part2(input: &str) -> u32 {
+    parse_input(input, true)
 }
 
-register_day!(2023, 1, part1, part2);
+register_day!(2023, 1, part1, part2; Metadata {
+    title: "Trebuchet?!",
+    tags: &[Tag::Parsing],
+    ..Metadata::EMPTY
+});
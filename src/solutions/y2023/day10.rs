@@ -1,27 +1,33 @@
 use std::collections::VecDeque;
 
-use crate::register_day;
+use crate::utils::Loc;
+use crate::visualize::{self, Color, Frame};
+use crate::{Metadata, Tag, register_day};
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 
 type Pos = (usize, usize); // (x, y)
 type Grid = Vec<Vec<Tile>>;
 
-#[allow(dead_code)]
-fn display_grid(grid: &Grid, path: &HashSet<Tile>) {
-    for row in grid.iter() {
-        for tile in row.iter() {
-            let icon = if path.contains(tile) {
-                tile.icon.to_string()
-            } else if tile.is_enclosed(grid, path) {
-                "\x1B[1;47mI\x1B[0m".to_string()
-            } else {
-                " ".to_string()
-            };
-            print!("{}", icon);
+fn visualize_loop(grid: &Grid, path: &HashSet<Tile>) -> Frame {
+    let enclosed = grid
+        .iter()
+        .flatten()
+        .filter(|tile| tile.is_enclosed(grid, path))
+        .map(|tile| Loc(tile.pos.1 as isize, tile.pos.0 as isize))
+        .collect::<HashSet<_>>();
+
+    Frame::from_fn(grid.len(), grid[0].len(), |y, x| {
+        let tile = &grid[y][x];
+        if path.contains(tile) {
+            tile.icon
+        } else if enclosed.contains(&Loc(y as isize, x as isize)) {
+            'I'
+        } else {
+            ' '
         }
-        println!();
-    }
+    })
+    .highlight(enclosed, Color::White)
 }
 
 fn pos_neighbors(pos: Pos, grid: &Grid) -> Vec<(Pos, Dir)> {
@@ -220,7 +226,7 @@ fn parse_input(input: &str) -> (Grid, Pos) {
     (grid, start)
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let (grid, start) = parse_input(input);
 
     let mut deq: VecDeque<(Tile, usize)> =
@@ -248,10 +254,10 @@ fn part1(input: &str) {
 
     let max_count = path.values().max().unwrap();
 
-    println!("Day 10 Part 1: {}", max_count);
+    *max_count
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> usize {
     let (grid, start) = parse_input(input);
 
     let mut deq: VecDeque<Tile> = VecDeque::from([grid[start.1][start.0].clone()]);
@@ -274,9 +280,14 @@ fn part2(input: &str) {
         .filter(|tile| tile.is_enclosed(&grid, &path))
         .count();
 
-    // display_grid(&grid, &path);
+    visualize::show(|| visualize_loop(&grid, &path));
 
-    println!("Day 10 Part 2: {}", enclosed_tiles);
+    enclosed_tiles
 }
 
-register_day!(2023, 10, part1, part2);
+register_day!(2023, 10, part1, part2; Metadata {
+    title: "Pipe Maze",
+    tags: &[Tag::Grid, Tag::Graph],
+    techniques: &["bfs", "ray casting", "visualize"],
+    ..Metadata::EMPTY
+});
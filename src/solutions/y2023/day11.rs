@@ -1,22 +1,14 @@
 use std::sync::OnceLock;
 
-use crate::register_day;
+use crate::utils;
+use crate::visualize::{self, Color, Frame};
+use crate::{Metadata, Tag, register_day};
 use grid::Grid;
 use hashbrown::HashMap;
 use itertools::Itertools;
 use priority_queue::DoublePriorityQueue;
 use rayon::iter::{ParallelBridge, ParallelIterator};
 
-#[allow(dead_code)]
-fn display_grid(grid: &Grid<char>) {
-    for row in grid.iter_rows() {
-        for item in row {
-            print!("{}", item);
-        }
-        println!();
-    }
-}
-
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 struct Loc {
     x: isize,
@@ -139,6 +131,11 @@ fn compute_distances(grid: &mut Grid<char>, factor: i64) -> Vec<i64> {
         })
         .collect_vec();
 
+    visualize::show(|| {
+        Frame::from_grid(grid)
+            .highlight(galaxies.iter().map(|g| utils::Loc(g.y, g.x)), Color::Yellow)
+    });
+
     galaxies
         .iter()
         .tuple_combinations()
@@ -147,16 +144,21 @@ fn compute_distances(grid: &mut Grid<char>, factor: i64) -> Vec<i64> {
         .collect()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> i64 {
     let mut grid = parse_input(input);
     let distances: Vec<i64> = compute_distances(&mut grid, 2);
-    println!("Day 11 Part 1: {}", distances.iter().sum::<i64>());
+    distances.iter().sum::<i64>()
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i64 {
     let mut grid = parse_input(input);
     let distances: Vec<i64> = compute_distances(&mut grid, 1_000_000);
-    println!("Day 11 Part 2: {}", distances.iter().sum::<i64>());
+    distances.iter().sum::<i64>()
 }
 
-register_day!(2023, 11, part1, part2);
+register_day!(2023, 11, part1, part2; Metadata {
+    title: "Cosmic Expansion",
+    tags: &[Tag::Grid, Tag::Geometry],
+    techniques: &["A*", "priority_queue", "visualize"],
+    ..Metadata::EMPTY
+});
@@ -1,6 +1,6 @@
-use crate::register_day;
-use cached::proc_macro::cached;
+use crate::{Metadata, Tag, register_day};
 use cached::SizedCache;
+use cached::proc_macro::cached;
 use itertools::Itertools;
 
 fn parse_input(input: &str) -> Vec<(String, Vec<u64>)> {
@@ -89,7 +89,7 @@ fn calc(record: &str, groups: &[u64]) -> u64 {
     }
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let mut sum = 0;
     for (s, v) in parse_input(input).iter() {
         let id = v.iter().fold(0, |x: usize, n| !(!x << n) << 1) >> 1;
@@ -114,11 +114,11 @@ fn part1(input: &str) {
 
         sum += k;
     }
-    println!("Day 12 Part 1: {}", sum);
+    sum
 }
 
-fn part2(input: &str) {
-    let sum = parse_input(input)
+fn part2(input: &str) -> u64 {
+    parse_input(input)
         .iter()
         .map(|(l, r)| {
             let mut s = format!("{}{}", l, "?").repeat(4);
@@ -126,8 +126,12 @@ fn part2(input: &str) {
             let v = r.repeat(5);
             calc(&s, &v)
         })
-        .sum::<u64>();
-    println!("Day 12 Part 2: {}", sum);
+        .sum::<u64>()
 }
 
-register_day!(2023, 12, part1, part2);
+register_day!(2023, 12, part1, part2; Metadata {
+    title: "Hot Springs",
+    tags: &[Tag::Dp],
+    techniques: &["cached"],
+    ..Metadata::EMPTY
+});
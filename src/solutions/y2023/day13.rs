@@ -1,17 +1,8 @@
-use crate::register_day;
+use crate::visualize::{self, Frame};
+use crate::{Metadata, Tag, register_day};
 use grid::Grid;
 use itertools::Itertools;
 
-#[allow(dead_code)]
-fn display_grid(grid: &Grid<char>) {
-    for row in grid.iter_rows() {
-        for item in row {
-            print!("{}", item);
-        }
-        println!();
-    }
-}
-
 fn parse_input(input: &str) -> Vec<Grid<char>> {
     input
         .split("\n\n")
@@ -38,19 +29,21 @@ fn get_reflection_axis(v: &Vec<Vec<&char>>) -> Vec<usize> {
     axis
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let mut sum = 0;
     for grid in parse_input(input).iter() {
+        visualize::show(|| Frame::from_grid(grid));
+
         let hor = grid.iter_rows().map(|v| v.collect_vec()).collect_vec();
         let ver = grid.iter_cols().map(|v| v.collect_vec()).collect_vec();
 
         sum += get_reflection_axis(&hor).first().unwrap_or(&0) * 100;
         sum += get_reflection_axis(&ver).first().unwrap_or(&0);
     }
-    println!("Day 13 Part 1: {}", sum);
+    sum
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> usize {
     let mut sum = 0;
     for grid in parse_input(input).iter() {
         let hor = grid.iter_rows().map(|v| v.collect_vec()).collect_vec();
@@ -93,7 +86,12 @@ fn part2(input: &str) {
 
         sum += val;
     }
-    println!("Day 13 Part 2: {}", sum);
+    sum
 }
 
-register_day!(2023, 13, part1, part2);
+register_day!(2023, 13, part1, part2; Metadata {
+    title: "Point of Incidence",
+    tags: &[Tag::Grid],
+    techniques: &["reflection axis", "visualize"],
+    ..Metadata::EMPTY
+});
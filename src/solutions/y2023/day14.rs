@@ -1,20 +1,11 @@
-use crate::register_day;
+use crate::visualize::{self, Frame};
+use crate::{Metadata, Tag, register_day};
 use grid::Grid;
 use hashbrown::HashMap;
 use itertools::Itertools;
 
 const MAX_CYCLES: usize = 1_000_000_000;
 
-#[allow(dead_code)]
-pub fn display_grid(grid: &Grid<char>) {
-    for row in grid.iter_rows() {
-        for item in row {
-            print!("{}", item);
-        }
-        println!();
-    }
-}
-
 fn parse_input(input: &str) -> Grid<char> {
     let cols = input.lines().next().unwrap().len();
     let chars = input.lines().flat_map(|line| line.chars()).collect_vec();
@@ -77,7 +68,7 @@ fn weight(grid: &Grid<char>) -> usize {
         .sum::<usize>()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let mut grid = parse_input(input);
 
     for i in 0..grid.rows() {
@@ -85,8 +76,9 @@ fn part1(input: &str) {
             roll(&mut grid, i, j, Direction::North);
         }
     }
+    visualize::show(|| Frame::from_grid(&grid));
 
-    println!("Day 14 Part 1: {}", weight(&grid));
+    weight(&grid)
 }
 
 fn roll_cycle(grid: &mut Grid<char>) {
@@ -115,13 +107,14 @@ fn roll_cycle(grid: &mut Grid<char>) {
     }
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> usize {
     let mut grid = parse_input(input);
 
     let mut store: HashMap<Vec<char>, usize> = HashMap::new();
     let (mut cycle_start, mut cycle_len) = (usize::MAX, usize::MAX);
     for idx in 1..MAX_CYCLES {
         roll_cycle(&mut grid);
+        visualize::animate(|| Frame::from_grid(&grid));
         let k = grid.clone().into_vec();
         if let Some(&n) = store.get(&k) {
             (cycle_start, cycle_len) = (n, idx - n);
@@ -136,7 +129,12 @@ fn part2(input: &str) {
         grid.cols(),
     );
 
-    println!("Day 14 Part 2: {}", weight(&cycled_grid));
+    weight(&cycled_grid)
 }
 
-register_day!(2023, 14, part1, part2);
+register_day!(2023, 14, part1, part2; Metadata {
+    title: "Parabolic Reflector Dish",
+    tags: &[Tag::Grid, Tag::Simulation],
+    techniques: &["cycle detection", "visualize"],
+    ..Metadata::EMPTY
+});
@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use hashbrown::HashMap;
 use itertools::Itertools;
 
@@ -39,7 +39,7 @@ fn parse_input(input: &str) -> HashMap<usize, Vec<Colors>> {
         .collect::<HashMap<usize, Vec<Colors>>>()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let games = parse_input(input);
     let mut impossible_ids = Vec::new();
 
@@ -54,15 +54,12 @@ fn part1(input: &str) {
 
     let sum_game_ids = games.keys().sum::<usize>();
 
-    println!(
-        "Day 2 Part 1: {}",
-        sum_game_ids - impossible_ids.iter().sum::<usize>()
-    );
+    sum_game_ids - impossible_ids.iter().sum::<usize>()
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> u32 {
     let games = parse_input(input);
-    let sum_powers = games
+    games
         .iter()
         .map(|(_, game)| {
             let max_r = game.iter().map(|&(r, _, _)| r).max().unwrap();
@@ -70,9 +67,11 @@ fn part2(input: &str) {
             let max_b = game.iter().map(|&(_, _, b)| b).max().unwrap();
             max_r * max_g * max_b
         })
-        .sum::<u32>();
-
-    println!("Day 2 Part 2: {}", sum_powers);
+        .sum::<u32>()
 }
 
-register_day!(2023, 2, part1, part2);
+register_day!(2023, 2, part1, part2; Metadata {
+    title: "Cube Conundrum",
+    tags: &[Tag::Parsing],
+    ..Metadata::EMPTY
+});
@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use hashbrown::HashSet;
 use itertools::Itertools;
 
@@ -63,7 +63,7 @@ fn find_possible_part_nums(input: &str) -> Vec<PartNum> {
     possible_parts
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> u32 {
     let grid: Grid = input.lines().map(|line| line.chars().collect()).collect();
     let nums = find_possible_part_nums(input)
         .iter()
@@ -72,10 +72,10 @@ fn part1(input: &str) {
         .map(|vec| concat(&vec))
         .collect_vec();
 
-    println!("Day 3 Part 1: {}", nums.iter().sum::<u32>());
+    nums.iter().sum::<u32>()
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> u32 {
     let grid: Grid = input.lines().map(|line| line.chars().collect()).collect();
     let possible_gears = grid
         .iter()
@@ -108,7 +108,12 @@ fn part2(input: &str) {
         }
     }
 
-    println!("Day 3 Part 2: {}", ratios.iter().sum::<u32>());
+    ratios.iter().sum::<u32>()
 }
 
-register_day!(2023, 3, part1, part2);
+register_day!(2023, 3, part1, part2; Metadata {
+    title: "Gear Ratios",
+    tags: &[Tag::Grid],
+    techniques: &["neighbour scan"],
+    ..Metadata::EMPTY
+});
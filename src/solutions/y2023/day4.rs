@@ -1,6 +1,6 @@
 use std::cell::RefCell;
 
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 
@@ -28,8 +28,8 @@ fn intersection_count(a: &[u32], b: &[u32]) -> u32 {
     a.intersection(&b).count() as u32
 }
 
-fn part1(input: &str) {
-    let points = parse_input(input)
+fn part1(input: &str) -> u32 {
+    parse_input(input)
         .iter()
         .map(|(l, r)| {
             let count = intersection_count(l, r);
@@ -39,12 +39,10 @@ fn part1(input: &str) {
                 u32::pow(2, count - 1)
             }
         })
-        .sum::<u32>();
-
-    println!("Day 4 Part 1: {}", points);
+        .sum::<u32>()
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> usize {
     // id, left, right, count
     type Numbers = Vec<u32>;
     type Count = RefCell<usize>;
@@ -62,12 +60,15 @@ fn part2(input: &str) {
         });
     }
 
-    let total_cards = cards
+    cards
         .values()
         .map(|(_, _, count)| *count.borrow())
-        .sum::<usize>();
-
-    println!("Day 4 Part 2: {}", total_cards);
+        .sum::<usize>()
 }
 
-register_day!(2023, 4, part1, part2);
+register_day!(2023, 4, part1, part2; Metadata {
+    title: "Scratchcards",
+    tags: &[Tag::Parsing],
+    techniques: &["set intersection"],
+    ..Metadata::EMPTY
+});
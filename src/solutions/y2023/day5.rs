@@ -1,7 +1,8 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day, register_generator, register_variant};
 use indicatif::{ParallelProgressIterator, ProgressStyle};
 use itertools::Itertools;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use std::ops::Range;
 
 type Seed = i64;
 
@@ -22,6 +23,38 @@ impl Map {
 
         destination
     }
+
+    /// Map a half-open range of sources, splitting it wherever it crosses a mapping boundary
+    fn map_range(&self, range: Range<i64>) -> Vec<Range<i64>> {
+        let mut unmapped = vec![range];
+        let mut mapped = Vec::new();
+
+        for &(drs, srs, l) in self.0.iter() {
+            let offset = drs - srs;
+            let mut rest = Vec::new();
+
+            for r in unmapped {
+                let start = r.start.max(srs);
+                let end = r.end.min(srs + l);
+                if start < end {
+                    mapped.push(start + offset..end + offset);
+                    if r.start < start {
+                        rest.push(r.start..start);
+                    }
+                    if end < r.end {
+                        rest.push(end..r.end);
+                    }
+                } else {
+                    rest.push(r);
+                }
+            }
+
+            unmapped = rest;
+        }
+
+        mapped.extend(unmapped);
+        mapped
+    }
 }
 
 fn parse_input(input: &str) -> (Vec<Seed>, Vec<Map>) {
@@ -57,19 +90,17 @@ fn map_through(maps: &[Map], seed: Seed) -> i64 {
     maps.iter().fold(seed, |acc, map| map.map(acc))
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> i64 {
     let (seeds, maps) = parse_input(input);
 
-    let loc = seeds
+    seeds
         .iter()
         .map(|&seed| map_through(&maps, seed))
         .min()
-        .unwrap();
-
-    println!("Day 5 Part 1: {}", loc);
+        .unwrap()
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i64 {
     let (seeds, maps) = parse_input(input);
 
     let style = ProgressStyle::default_bar()
@@ -86,14 +117,77 @@ fn part2(input: &str) {
         .flat_map(|it| it.clone())
         .collect_vec();
 
-    let loc = expanded_seeds
+    expanded_seeds
         .par_iter()
         .progress_with_style(style)
         .map(|&seed| map_through(&maps, seed))
         .min()
-        .unwrap();
+        .unwrap()
+}
+
+/// Push whole seed ranges through the maps instead of expanding every seed
+fn part2_ranges(input: &str) -> i64 {
+    let (seeds, maps) = parse_input(input);
+
+    let ranges = seeds
+        .iter()
+        .tuples()
+        .map(|(&start, &len)| start..start + len)
+        .collect_vec();
+
+    maps.iter()
+        .fold(ranges, |ranges, map| {
+            ranges
+                .into_iter()
+                .flat_map(|range| map.map_range(range))
+                .collect()
+        })
+        .iter()
+        .map(|range| range.start)
+        .min()
+        .unwrap()
+}
 
-    println!("Day 5 Part 2: {}", loc);
+/// Seed ranges and seven maps of `size` non-overlapping mappings each. Seed ranges stay
+/// short so the brute-force part 2 finishes.
+fn generate(rng: &mut fastrand::Rng, size: usize) -> String {
+    const MAPS: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+    let size = size.max(1);
+    let span = size as i64 * 1000;
+
+    let seeds = (0..size.div_ceil(4))
+        .flat_map(|_| [rng.i64(0..span), rng.i64(1..=1000)])
+        .join(" ");
+    let maps = MAPS.iter().map(|name| {
+        // Each mapping gets its own slice of the source span so none overlap
+        let width = span / size as i64;
+        let mappings = (0..size as i64)
+            .map(|i| {
+                let len = rng.i64(1..=width);
+                let src = i * width + rng.i64(0..=width - len);
+                let dst = rng.i64(0..span);
+                format!("{dst} {src} {len}")
+            })
+            .join("\n");
+        format!("{name} map:\n{mappings}")
+    });
+
+    format!("seeds: {seeds}\n\n{}", maps.collect_vec().join("\n\n"))
 }
 
-register_day!(2023, 5, part1, part2);
+register_day!(2023, 5, part1, part2; Metadata {
+    title: "If You Give A Seed A Fertilizer",
+    tags: &[Tag::Geometry],
+    techniques: &["range splitting", "rayon", "indicatif"],
+    notes: "Part 2 expands every seed by default; the `ranges` variant maps whole ranges instead",
+});
+register_variant!(2023, 5, 2, "ranges", part2_ranges);
+register_generator!(2023, 5, generate);
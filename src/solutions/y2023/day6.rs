@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use itertools::Itertools;
 
 fn parse(text: &str) -> Vec<u64> {
@@ -22,10 +22,10 @@ fn parse_input(input: &str) -> (Vec<u64>, Vec<u64>) {
     (parse(times), parse(dists))
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> u64 {
     let (times, dists) = parse_input(input);
 
-    let res = times
+    times
         .iter()
         .zip(dists.iter())
         .map(|(time, dist)| {
@@ -34,21 +34,22 @@ fn part1(input: &str) {
                 .filter(|t| t > dist)
                 .count() as u64
         })
-        .product::<u64>();
-
-    println!("Day 6 Part 1: {}", res);
+        .product::<u64>()
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> u64 {
     let (times, dists) = parse_input(input);
     let (time, dist) = (concat(&times), concat(&dists));
 
-    let res = (0..=time)
+    (0..=time)
         .map(|t| t * (time - t))
         .filter(|&t| t > dist)
-        .count() as u64;
-
-    println!("Day 6 Part 2: {}", res);
+        .count() as u64
 }
 
-register_day!(2023, 6, part1, part2);
+register_day!(2023, 6, part1, part2; Metadata {
+    title: "Wait For It",
+    tags: &[Tag::Math],
+    techniques: &["brute force"],
+    ..Metadata::EMPTY
+});
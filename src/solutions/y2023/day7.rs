@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use fnv::FnvHashMap;
 use itertools::Itertools;
 
@@ -85,7 +85,7 @@ fn parse_input(input: &str) -> Vec<(Hand, u32)> {
         .collect_vec()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> u32 {
     let replaced = input
         .replace('A', "E")
         .replace('K', "D")
@@ -95,16 +95,14 @@ fn part1(input: &str) {
     let mut hands = parse_input(&replaced);
     hands.sort_by_cached_key(|(hand, _)| hand.score());
 
-    let winnings = hands
+    hands
         .iter()
         .enumerate()
         .map(|(i, (_, bid))| bid * (i as u32 + 1))
-        .sum::<u32>();
-
-    println!("Day 7 Part 1: {}", winnings);
+        .sum::<u32>()
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> u32 {
     let replaced = input
         .replace('A', "E")
         .replace('K', "D")
@@ -114,13 +112,16 @@ fn part2(input: &str) {
     let mut hands = parse_input(&replaced);
     hands.sort_by_cached_key(|(hand, _)| hand.score_joker());
 
-    let winnings = hands
+    hands
         .iter()
         .enumerate()
         .map(|(i, (_, bid))| bid * (i as u32 + 1))
-        .sum::<u32>();
-
-    println!("Day 7 Part 2: {}", winnings);
+        .sum::<u32>()
 }
 
-register_day!(2023, 7, part1, part2);
+register_day!(2023, 7, part1, part2; Metadata {
+    title: "Camel Cards",
+    tags: &[Tag::Parsing],
+    techniques: &["custom sort key"],
+    ..Metadata::EMPTY
+});
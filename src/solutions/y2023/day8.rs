@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use hashbrown::HashMap;
 use itertools::Itertools;
 use num::integer::lcm;
@@ -15,7 +15,7 @@ fn parse_input(input: &str) -> (Vec<char>, HashMap<String, (String, String)>) {
     (steps.chars().collect_vec(), nodes)
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let (steps, nodes) = parse_input(input);
 
     let mut curr_node = "AAA";
@@ -23,8 +23,7 @@ fn part1(input: &str) {
 
     loop {
         if curr_node == "ZZZ" {
-            println!("Day 8 Part 1: {}", curr_steps);
-            break;
+            return curr_steps;
         }
 
         let side = steps[curr_steps % steps.len()];
@@ -38,12 +37,12 @@ fn part1(input: &str) {
     }
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> u64 {
     let (steps, nodes) = parse_input(input);
 
     let start_nodes = nodes.keys().filter(|k| k.ends_with('A')).collect_vec();
 
-    let lcm = start_nodes
+    start_nodes
         .iter()
         .map(|&node| {
             let mut curr_node = node;
@@ -66,9 +65,12 @@ fn part2(input: &str) {
 
             curr_steps as u64
         })
-        .fold(1, lcm);
-
-    println!("Day 8 Part 2: {}", lcm);
+        .fold(1, lcm)
 }
 
-register_day!(2023, 8, part1, part2);
+register_day!(2023, 8, part1, part2; Metadata {
+    title: "Haunted Wasteland",
+    tags: &[Tag::Graph, Tag::Math],
+    techniques: &["lcm"],
+    notes: "Part 2 relies on every start node reaching its end node on a clean cycle",
+});
@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, checked, register_day};
 use itertools::Itertools;
 
 fn parse_input(input: &str) -> Vec<Vec<i32>> {
@@ -12,15 +12,20 @@ fn parse_input(input: &str) -> Vec<Vec<i32>> {
         .collect()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> i32 {
     let sequences = parse_input(input);
-    let res = sequences
+
+    let predictions = sequences
         .iter()
         .map(|orig_seq| {
             let mut seq = orig_seq.clone();
             let mut intermediate_seqs = vec![];
             loop {
-                let diffs = seq.iter().tuple_windows().map(|(a, b)| b - a).collect_vec();
+                let diffs = seq
+                    .iter()
+                    .tuple_windows()
+                    .map(|(&a, &b)| checked::sub(b, a, "differences"))
+                    .collect_vec();
                 if diffs.iter().all(|&x| x == 0) {
                     break;
                 }
@@ -28,27 +33,30 @@ fn part1(input: &str) {
                 intermediate_seqs.push(diffs.clone());
                 seq = diffs;
             }
-            let final_diff = intermediate_seqs
-                .iter()
-                .map(|v| v.last().unwrap())
-                .sum::<i32>();
+            let final_diff = checked::sum(
+                intermediate_seqs.iter().map(|v| *v.last().unwrap()),
+                "extrapolation",
+            );
             (orig_seq, final_diff)
         })
-        .map(|(seq, diff)| seq.last().unwrap() + diff)
-        .sum::<i32>();
-
-    println!("Day 9 Part 1: {}", res);
+        .map(|(seq, diff)| checked::add(*seq.last().unwrap(), diff, "extrapolation"));
+    checked::sum(predictions, "prediction total")
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i32 {
     let sequences = parse_input(input);
-    let res = sequences
+
+    let predictions = sequences
         .iter()
         .map(|orig_seq| {
             let mut seq = orig_seq.clone();
             let mut intermediate_seqs = vec![];
             loop {
-                let diffs = seq.iter().tuple_windows().map(|(a, b)| b - a).collect_vec();
+                let diffs = seq
+                    .iter()
+                    .tuple_windows()
+                    .map(|(&a, &b)| checked::sub(b, a, "differences"))
+                    .collect_vec();
                 if diffs.iter().all(|&x| x == 0) {
                     break;
                 }
@@ -60,13 +68,16 @@ fn part2(input: &str) {
                 .iter()
                 .rev()
                 .map(|v| v.first().unwrap())
-                .fold(0, |acc, &x| x - acc);
+                .fold(0, |acc, &x| checked::sub(x, acc, "extrapolation"));
             (orig_seq, first_diff)
         })
-        .map(|(seq, diff)| seq.first().unwrap() - diff)
-        .sum::<i32>();
-
-    println!("Day 9 Part 2: {}", res);
+        .map(|(seq, diff)| checked::sub(*seq.first().unwrap(), diff, "extrapolation"));
+    checked::sum(predictions, "prediction total")
 }
 
-register_day!(2023, 9, part1, part2);
+register_day!(2023, 9, part1, part2; Metadata {
+    title: "Mirage Maintenance",
+    tags: &[Tag::Math],
+    techniques: &["finite differences"],
+    ..Metadata::EMPTY
+});
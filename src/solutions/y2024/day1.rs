@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use itertools::Itertools;
 
 fn parse_input(input: &str) -> (Vec<i32>, Vec<i32>) {
@@ -13,19 +13,16 @@ fn parse_input(input: &str) -> (Vec<i32>, Vec<i32>) {
         .unzip()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> i32 {
     let (l, r) = parse_input(input);
 
-    let total_distance = l
-        .into_iter()
+    l.into_iter()
         .sorted()
         .zip(r.into_iter().sorted())
-        .fold(0, |acc, (a, b)| acc + (a - b).abs());
-
-    println!("Day 1 Part 1: {}", total_distance);
+        .fold(0, |acc, (a, b)| acc + (a - b).abs())
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i32 {
     let (l, r) = parse_input(input);
     let r_counts = r.into_iter().counts();
 
@@ -34,7 +31,12 @@ fn part2(input: &str) {
         .filter_map(|x| r_counts.get(&x).map(|&count| count as i32 * x))
         .sum();
 
-    println!("Day 1 Part 2: {}", similarity_score);
+    similarity_score
 }
 
-register_day!(2024, 1, part1, part2);
+register_day!(2024, 1, part1, part2; Metadata {
+    title: "Historian Hysteria",
+    tags: &[Tag::Parsing],
+    techniques: &["counts"],
+    ..Metadata::EMPTY
+});
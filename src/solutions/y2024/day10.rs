@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 
-use crate::register_day;
-use crate::utils::{GridUtils, Loc, DIR4};
+use crate::utils::{DIR4, GridUtils, Loc};
+use crate::{Metadata, Tag, register_day};
 use grid::Grid;
 use hashbrown::HashSet;
 use itertools::Itertools;
@@ -13,7 +13,7 @@ fn parse_input(input: &str) -> (Grid<char>, HashSet<Loc>) {
     (grid, trailheads)
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> i32 {
     let (map, trailheads) = parse_input(input);
 
     let total_score: i32 = trailheads
@@ -42,10 +42,10 @@ fn part1(input: &str) {
         })
         .sum();
 
-    println!("Day 10 Part 1: {}", total_score);
+    total_score
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i32 {
     let (map, trailheads) = parse_input(input);
 
     let total_score: i32 = trailheads
@@ -67,7 +67,12 @@ fn part2(input: &str) {
         })
         .sum();
 
-    println!("Day 10 Part 2: {}", total_score);
+    total_score
 }
 
-register_day!(2024, 10, part1, part2);
+register_day!(2024, 10, part1, part2; Metadata {
+    title: "Hoof It",
+    tags: &[Tag::Grid, Tag::Graph],
+    techniques: &["bfs", "GridUtils"],
+    ..Metadata::EMPTY
+});
@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, checked, register_day, register_generator};
 use fnv::FnvHashMap;
 use itertools::Itertools;
 
@@ -37,7 +37,7 @@ fn apply_rules(stone: StoneId) -> (StoneId, Option<StoneId>) {
     match stone {
         0 => (1, None),
         val if stone.n_digits() % 2 == 0 => (val.split_half().0, Some(val.split_half().1)),
-        _ => (stone * 2024, None),
+        _ => (checked::mul(stone, 2024, "stone engravings"), None),
     }
 }
 
@@ -50,9 +50,11 @@ fn compute(n_steps: usize, mut stones: FnvHashMap<StoneId, Count>) -> Count {
 
         for (&stone, &count) in &stones {
             let (a, b) = apply_rules(stone);
-            *new_stones.entry(a).or_default() += count;
+            let entry = new_stones.entry(a).or_default();
+            *entry = checked::add(*entry, count, "stone counts");
             if let Some(b) = b {
-                *new_stones.entry(b).or_default() += count;
+                let entry = new_stones.entry(b).or_default();
+                *entry = checked::add(*entry, count, "stone counts");
             }
         }
 
@@ -60,17 +62,26 @@ fn compute(n_steps: usize, mut stones: FnvHashMap<StoneId, Count>) -> Count {
         std::mem::swap(&mut stones, &mut new_stones);
     }
 
-    stones.values().sum()
+    checked::sum(stones.values().copied(), "stone counts")
 }
 
-fn part1(input: &str) {
-    let total = compute(25, parse_input(input));
-    println!("Day 11 Part 1: {}", total);
+fn part1(input: &str) -> usize {
+    compute(25, parse_input(input))
 }
 
-fn part2(input: &str) {
-    let total = compute(75, parse_input(input));
-    println!("Day 11 Part 2: {}", total);
+fn part2(input: &str) -> usize {
+    compute(75, parse_input(input))
 }
 
-register_day!(2024, 11, part1, part2);
+/// `size` stones with engravings of up to seven digits
+fn generate(rng: &mut fastrand::Rng, size: usize) -> String {
+    (0..size).map(|_| rng.u64(0..10_000_000)).join(" ")
+}
+
+register_day!(2024, 11, part1, part2; Metadata {
+    title: "Plutonian Pebbles",
+    tags: &[Tag::Dp, Tag::Simulation],
+    techniques: &["count map"],
+    ..Metadata::EMPTY
+});
+register_generator!(2024, 11, generate);
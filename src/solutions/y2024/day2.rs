@@ -1,5 +1,5 @@
-use crate::register_day;
-use itertools::{any, Itertools};
+use crate::{Metadata, register_day};
+use itertools::{Itertools, any};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 fn parse_input(input: &str) -> Vec<Vec<i32>> {
@@ -23,17 +23,15 @@ fn safe_check(line: &[i32]) -> bool {
     diffs.iter().all(|&x| (1..=3).contains(&x)) || diffs.iter().all(|&x| (-3..=-1).contains(&x))
 }
 
-fn part1(input: &str) {
-    let safe_count = parse_input(input)
+fn part1(input: &str) -> usize {
+    parse_input(input)
         .iter()
         .filter(|line| safe_check(line))
-        .count();
-
-    println!("Day 2 Part 1: {}", safe_count);
+        .count()
 }
 
-fn part2(input: &str) {
-    let safe_count = parse_input(input)
+fn part2(input: &str) -> usize {
+    parse_input(input)
         .par_iter()
         .filter(|line| {
             safe_check(line)
@@ -42,9 +40,11 @@ fn part2(input: &str) {
                     |iter| safe_check(&iter),
                 )
         })
-        .count();
-
-    println!("Day 2 Part 2: {}", safe_count);
+        .count()
 }
 
-register_day!(2024, 2, part1, part2);
+register_day!(2024, 2, part1, part2; Metadata {
+    title: "Red-Nosed Reports",
+    techniques: &["rayon"],
+    ..Metadata::EMPTY
+});
@@ -1,24 +1,20 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use regex::Regex;
 
-fn part1(input: &str) {
+fn part1(input: &str) -> i32 {
     let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
-    let total = re
-        .captures_iter(input)
+    re.captures_iter(input)
         .map(|caps| {
             let (_, [l, r]) = caps.extract();
             (l.parse::<i32>().unwrap(), r.parse::<i32>().unwrap())
         })
-        .fold(0, |acc, (l, r)| acc + l * r);
-
-    println!("Day 3 Part 1: {}", total);
+        .fold(0, |acc, (l, r)| acc + l * r)
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i32 {
     let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|don't|do").unwrap();
     let mut enabled = true;
-    let total = re
-        .captures_iter(input)
+    re.captures_iter(input)
         .filter_map(|cap| match (&cap[0], enabled) {
             ("don't", _) => {
                 enabled = false;
@@ -34,9 +30,12 @@ fn part2(input: &str) {
             )),
             _ => None,
         })
-        .fold(0, |acc, (l, r)| acc + l * r);
-
-    println!("Day 3 Part 2: {}", total);
+        .fold(0, |acc, (l, r)| acc + l * r)
 }
 
-register_day!(2024, 3, part1, part2);
+register_day!(2024, 3, part1, part2; Metadata {
+    title: "Mull It Over",
+    tags: &[Tag::Parsing],
+    techniques: &["regex"],
+    ..Metadata::EMPTY
+});
@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use grid::Grid;
 use itertools::Itertools;
 
@@ -38,16 +38,14 @@ fn get_words(grid: &Grid<char>, r: usize, c: usize) -> Vec<String> {
     words
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let grid = parse_input(input);
-    let total_xmas = (0..grid.rows())
+    (0..grid.rows())
         .cartesian_product(0..grid.cols())
         .filter(|&(r, c)| grid[(r, c)] == 'X')
         .flat_map(|(r, c)| get_words(&grid, r, c))
         .filter(|word| word == "XMAS")
-        .count();
-
-    println!("Day 4 Part 1: {:?}", total_xmas);
+        .count()
 }
 
 fn check_diagonal_sam(chars: [Option<&char>; 3]) -> bool {
@@ -55,7 +53,7 @@ fn check_diagonal_sam(chars: [Option<&char>; 3]) -> bool {
     word == "MAS" || word == "SAM"
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i32 {
     let grid = parse_input(input);
     let mut total_cross_mas = 0;
 
@@ -81,7 +79,12 @@ fn part2(input: &str) {
         }
     }
 
-    println!("Day 4 Part 2: {}", total_cross_mas);
+    total_cross_mas
 }
 
-register_day!(2024, 4, part1, part2);
+register_day!(2024, 4, part1, part2; Metadata {
+    title: "Ceres Search",
+    tags: &[Tag::Grid],
+    techniques: &["word search"],
+    ..Metadata::EMPTY
+});
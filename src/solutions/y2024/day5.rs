@@ -1,6 +1,6 @@
 use std::cmp::Ordering;
 
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 
@@ -34,7 +34,7 @@ fn parse_input(input: &str) -> (HashMap<i32, HashSet<i32>>, Vec<Vec<i32>>) {
     (rules_map, updates_vec)
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> i32 {
     let (rules_map, updates) = parse_input(input);
 
     let mut valids = vec![];
@@ -52,10 +52,10 @@ fn part1(input: &str) {
 
     let total: i32 = valids.iter().map(|v| v[v.len() / 2]).sum();
 
-    println!("Day 5 Part 1: {}", total);
+    total
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i32 {
     let (rules_map, updates) = parse_input(input);
 
     let mut invalids = vec![];
@@ -86,7 +86,12 @@ fn part2(input: &str) {
 
     let total: i32 = new_invalids.iter().map(|v| v[v.len() / 2]).sum();
 
-    println!("Day 5 Part 2: {}", total);
+    total
 }
 
-register_day!(2024, 5, part1, part2);
+register_day!(2024, 5, part1, part2; Metadata {
+    title: "Print Queue",
+    tags: &[Tag::Graph],
+    techniques: &["custom Ordering"],
+    ..Metadata::EMPTY
+});
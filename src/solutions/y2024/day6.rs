@@ -1,5 +1,5 @@
-use crate::register_day;
 use crate::utils::{Dir, GridUtils, Loc};
+use crate::{Metadata, Tag, register_day};
 use fnv::FnvHashSet;
 use grid::Grid;
 use rayon::iter::{ParallelBridge, ParallelIterator};
@@ -81,20 +81,20 @@ fn parse_input(input: &str) -> (Grid<char>, Loc) {
     (grid, loc)
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let (grid, starting_pos) = parse_input(input);
     let walked_locs = walk(&grid, &starting_pos);
 
-    println!("Day 6 Part 1: {}", walked_locs.len());
+    walked_locs.len()
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> usize {
     let (grid, starting_pos) = parse_input(input);
     let mut walked_locs: FnvHashSet<Loc> = walk(&grid, &starting_pos);
 
     walked_locs.remove(&starting_pos);
 
-    let cycle_count = walked_locs
+    walked_locs
         .iter()
         .par_bridge()
         .filter_map(|&pos| {
@@ -128,9 +128,12 @@ fn part2(input: &str) {
             }
             None
         })
-        .count();
-
-    println!("Day 6 Part 2: {}", cycle_count);
+        .count()
 }
 
-register_day!(2024, 6, part1, part2);
+register_day!(2024, 6, part1, part2; Metadata {
+    title: "Guard Gallivant",
+    tags: &[Tag::Grid, Tag::Simulation],
+    techniques: &["GridUtils", "loop detection", "rayon"],
+    ..Metadata::EMPTY
+});
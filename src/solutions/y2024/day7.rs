@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, checked, register_day};
 use dashmap::DashMap;
 use itertools::Itertools;
 use rayon::prelude::*;
@@ -13,9 +13,13 @@ enum Op {
 impl Op {
     fn invoke(&self, a: u64, b: u64) -> u64 {
         match self {
-            Op::Add => a + b,
-            Op::Mul => a * b,
-            Op::Cat => 10_u64.pow(b.ilog10() + 1) * a + b,
+            Op::Add => checked::add(a, b, "Op::Add"),
+            Op::Mul => checked::mul(a, b, "Op::Mul"),
+            Op::Cat => checked::add(
+                checked::mul(10_u64.pow(b.ilog10() + 1), a, "Op::Cat"),
+                b,
+                "Op::Cat",
+            ),
         }
     }
 }
@@ -80,7 +84,7 @@ fn compute_recurse(vals: &[u64], op_options: &[Op], target: u64) -> Option<u64>
         .find_map(|op| recurse(vals[0], &vals[1..], *op, op_options, target))
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> u64 {
     let total: u64 = parse_input(input)
         .par_iter()
         .filter_map(|(target, components)| {
@@ -93,11 +97,11 @@ fn part1(input: &str) {
                 }
             })
         })
-        .sum();
-    println!("Day 7 Part 1: {}", total);
+        .reduce(|| 0, |a, b| checked::add(a, b, "calibration total"));
+    total
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> u64 {
     let cached_ops: DashMap<usize, Vec<Vec<Op>>> = DashMap::new();
     let total: u64 = parse_input(input)
         .par_iter()
@@ -113,8 +117,13 @@ fn part2(input: &str) {
                 }
             })
         })
-        .sum();
-    println!("Day 7 Part 2: {}", total);
+        .reduce(|| 0, |a, b| checked::add(a, b, "calibration total"));
+    total
 }
 
-register_day!(2024, 7, part1, part2);
+register_day!(2024, 7, part1, part2; Metadata {
+    title: "Bridge Repair",
+    tags: &[Tag::Math],
+    techniques: &["operator search", "rayon", "DashMap"],
+    ..Metadata::EMPTY
+});
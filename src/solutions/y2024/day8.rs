@@ -1,7 +1,7 @@
 use std::iter::successors;
 
-use crate::register_day;
 use crate::utils::Loc;
+use crate::{Metadata, Tag, register_day};
 use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 
@@ -28,7 +28,7 @@ fn parse_input(input: &str) -> (HashMap<char, Vec<Loc>>, (usize, usize)) {
     (antennas, (rows, cols))
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let (antennas, bounds) = parse_input(input);
 
     let mut antinodes: HashSet<Loc> = HashSet::new();
@@ -44,10 +44,10 @@ fn part1(input: &str) {
         });
     });
 
-    println!("Day 8 Part 1: {}", antinodes.len());
+    antinodes.len()
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> usize {
     let (antennas, bounds) = parse_input(input);
 
     let mut antinodes: HashSet<Loc> = HashSet::new();
@@ -63,7 +63,12 @@ fn part2(input: &str) {
         });
     });
 
-    println!("Day 8 Part 2: {}", antinodes.len());
+    antinodes.len()
 }
 
-register_day!(2024, 8, part1, part2);
+register_day!(2024, 8, part1, part2; Metadata {
+    title: "Resonant Collinearity",
+    tags: &[Tag::Grid, Tag::Geometry],
+    techniques: &["combinations"],
+    ..Metadata::EMPTY
+});
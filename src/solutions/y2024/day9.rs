@@ -1,6 +1,6 @@
 use std::{cmp::min, collections::LinkedList};
 
-use crate::register_day;
+use crate::{Metadata, Tag, debug, register_day, register_generator, trace};
 use hashbrown::HashSet;
 use itertools::Itertools;
 
@@ -25,26 +25,21 @@ impl Segment {
     }
 }
 
-#[allow(dead_code)]
-fn vis<'a, T>(segments: T)
+/// Render the disk map like the puzzle description, e.g. `00...111`
+fn vis<'a, T>(segments: T) -> String
 where
     T: IntoIterator<Item = &'a Segment>,
 {
-    for segment in segments {
-        match segment.segment_type {
-            SegmentType::Free => {
-                (0..segment.len).for_each(|_| {
-                    print!(".");
-                });
-            }
-            SegmentType::Filled(id) => {
-                (0..segment.len).for_each(|_| {
-                    print!("{id}");
-                });
-            }
-        }
-    }
-    println!();
+    segments
+        .into_iter()
+        .map(|segment| {
+            let c = match segment.segment_type {
+                SegmentType::Free => ".".to_string(),
+                SegmentType::Filled(id) => id.to_string(),
+            };
+            c.repeat(segment.len as usize)
+        })
+        .collect()
 }
 
 fn checksum<'a, T>(segments: T) -> i64
@@ -100,7 +95,7 @@ fn parse_input(input: &str) -> LinkedList<Segment> {
     list
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> i64 {
     let mut list = parse_input(input);
     let mut new_list = LinkedList::new();
     new_list.push_back(list.pop_front().unwrap());
@@ -149,10 +144,12 @@ fn part1(input: &str) {
         }
     }
 
-    println!("Day 9 Part 1: {}", checksum(&new_list));
+    debug!("{}", vis(&new_list));
+
+    checksum(&new_list)
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i64 {
     let mut vec = parse_input(input).into_iter().collect_vec();
     let file_ids: HashSet<i64> = vec
         .iter()
@@ -209,9 +206,30 @@ fn part2(input: &str) {
             }
         }
         locked_ids.insert(unlocked_file.id().unwrap());
+        trace!("{}", vis(&vec));
     }
 
-    println!("Day 9 Part 2: {}", checksum(&vec));
+    checksum(&vec)
+}
+
+/// A disk map of `size` files with a free gap of 0-9 blocks between each pair
+fn generate(rng: &mut fastrand::Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|i| {
+            let file = rng.u8(1..=9).to_string();
+            if i + 1 < size {
+                file + &rng.u8(0..=9).to_string()
+            } else {
+                file
+            }
+        })
+        .collect()
 }
 
-register_day!(2024, 9, part1, part2);
+register_day!(2024, 9, part1, part2; Metadata {
+    title: "Disk Fragmenter",
+    tags: &[Tag::Simulation],
+    techniques: &["LinkedList"],
+    ..Metadata::EMPTY
+});
+register_generator!(2024, 9, generate);
@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 
 fn parse_input(input: &str) -> Vec<(char, i32)> {
     input
@@ -7,20 +7,18 @@ fn parse_input(input: &str) -> Vec<(char, i32)> {
         .collect()
 }
 
-fn part1(input: &str) {
-    let count = parse_input(input)
+fn part1(input: &str) -> usize {
+    parse_input(input)
         .into_iter()
         .scan(50, |pos, (dir, dist)| {
             *pos = (*pos + if dir == 'R' { dist } else { -dist }).rem_euclid(100);
             Some(*pos)
         })
         .filter(|&pos| pos == 0)
-        .count();
-
-    println!("Day 1 Part 1: {}", count);
+        .count()
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i32 {
     let (mut position, mut count) = (50, 0);
     for (dir, dist) in parse_input(input) {
         for _ in 0..dist {
@@ -31,7 +29,12 @@ fn part2(input: &str) {
         }
     }
 
-    println!("Day 1 Part 2: {}", count);
+    count
 }
 
-register_day!(2025, 1, part1, part2);
+register_day!(2025, 1, part1, part2; Metadata {
+    title: "Secret Entrance",
+    tags: &[Tag::Simulation, Tag::Math],
+    techniques: &["modular arithmetic"],
+    ..Metadata::EMPTY
+});
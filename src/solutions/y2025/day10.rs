@@ -1,7 +1,7 @@
 use hashbrown::HashSet;
 use std::collections::VecDeque;
 
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 
 struct Button {
     #[allow(dead_code)]
@@ -89,10 +89,10 @@ fn parse_input(input: &str) -> Vec<Machine> {
     input.lines().map(Machine::new_from_str).collect()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> u32 {
     let machines = parse_input(input);
 
-    let sum = machines
+    machines
         .iter()
         .map(|machine| {
             // state, presses
@@ -113,9 +113,12 @@ fn part1(input: &str) {
                 });
             }
         })
-        .sum::<u32>();
-
-    println!("Day 10 Part 1: {}", sum);
+        .sum::<u32>()
 }
 
-register_day!(2025, 10, part1);
+register_day!(2025, 10, part1; Metadata {
+    title: "Factory",
+    tags: &[Tag::Graph],
+    techniques: &["bfs", "bitmasks"],
+    ..Metadata::EMPTY
+});
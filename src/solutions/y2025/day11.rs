@@ -1,6 +1,8 @@
 use hashbrown::HashMap;
 
-use crate::register_day;
+use crate::{Metadata, Tag, checked, register_day, register_variant, timing::phase};
+use num::traits::{CheckedAdd, CheckedMul};
+use num::{BigUint, One, Zero};
 use std::collections::VecDeque;
 
 struct Node {
@@ -89,46 +91,78 @@ fn topo_sort(graph: &Graph) -> Vec<String> {
     sorted
 }
 
-fn count_paths(graph: &Graph, sorted: &Vec<String>, start: &str, end: &str) -> u64 {
-    let mut ways: HashMap<String, u64> = graph.keys().map(|id| (id.clone(), 0u64)).collect();
-    ways.insert(start.to_string(), 1);
+fn count_paths<T: Clone + CheckedAdd + Zero + One>(
+    graph: &Graph,
+    sorted: &Vec<String>,
+    start: &str,
+    end: &str,
+) -> T {
+    let mut ways: HashMap<String, T> = graph.keys().map(|id| (id.clone(), T::zero())).collect();
+    ways.insert(start.to_string(), T::one());
 
     for node_id in sorted {
-        let count = ways[node_id];
+        let count = ways[node_id].clone();
         for neighbor in &graph[node_id].outgoing {
-            *ways.get_mut(neighbor).unwrap() += count;
+            let ways = ways.get_mut(neighbor).unwrap();
+            *ways = checked::add(ways.clone(), count.clone(), "path counts");
         }
     }
 
-    ways.get(end).cloned().unwrap_or(0)
+    ways.get(end).cloned().unwrap_or(T::zero())
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> u64 {
+    phase("parse");
     let graph = parse_input(input);
+    phase("topo sort");
     let sorted = topo_sort(&graph);
 
-    let paths = count_paths(&graph, &sorted, "you", "out");
-
-    println!("Day 11 Part 1: {}", paths);
+    phase("count paths");
+    count_paths::<u64>(&graph, &sorted, "you", "out")
 }
 
-fn part2(input: &str) {
-    let graph = parse_input(input);
-    let sorted = topo_sort(&graph);
-
-    let prod = |path: &[(&str, &str)]| -> u64 {
-        path.iter()
-            .map(|(a, b)| count_paths(&graph, &sorted, a, b))
-            .product()
+/// Paths through both `dac` and `fft`, in whichever order the graph allows
+fn count_via_dac_fft<T: Clone + CheckedAdd + CheckedMul + Zero + One>(
+    graph: &Graph,
+    sorted: &Vec<String>,
+) -> T {
+    let prod = |path: &[(&str, &str)]| -> T {
+        checked::product(
+            path.iter().map(|(a, b)| count_paths(graph, sorted, a, b)),
+            "path products",
+        )
     };
 
     // path type 1: svr -> dac -> fft -> ou
-    let c1: u64 = prod(&[("svr", "dac"), ("dac", "fft"), ("fft", "out")]);
+    let c1 = prod(&[("svr", "dac"), ("dac", "fft"), ("fft", "out")]);
 
     // path type 2: svr -> fft -> dac -> out
-    let c2: u64 = prod(&[("svr", "fft"), ("fft", "dac"), ("dac", "out")]);
+    let c2 = prod(&[("svr", "fft"), ("fft", "dac"), ("dac", "out")]);
 
-    println!("Day 11 Part 2: {}", c1 + c2);
+    checked::add(c1, c2, "path totals")
+}
+
+fn part2(input: &str) -> u64 {
+    phase("parse");
+    let graph = parse_input(input);
+    phase("topo sort");
+    let sorted = topo_sort(&graph);
+
+    phase("count paths");
+    count_via_dac_fft(&graph, &sorted)
+}
+
+/// Count with arbitrary precision, for graphs with more paths than fit in a `u64`
+fn part2_bigint(input: &str) -> BigUint {
+    let graph = parse_input(input);
+    let sorted = topo_sort(&graph);
+    count_via_dac_fft(&graph, &sorted)
 }
 
-register_day!(2025, 11, part1, part2);
+register_day!(2025, 11, part1, part2; Metadata {
+    title: "Reactor",
+    tags: &[Tag::Graph, Tag::Dp],
+    techniques: &["topological sort", "path counting"],
+    ..Metadata::EMPTY
+});
+register_variant!(2025, 11, 2, "bigint", part2_bigint);
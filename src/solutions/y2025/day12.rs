@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, info, register_day};
 
 type ShapeArea = u64;
 struct Region {
@@ -34,7 +34,7 @@ fn dot_prod(a: &Vec<u64>, b: &Vec<u64>) -> u64 {
     a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> u64 {
     let (shapes, regions) = parse_input(input);
 
     let (mut no, mut maybe, mut yes) = (0u64, 0u64, 0u64);
@@ -51,13 +51,14 @@ fn part1(input: &str) {
         }
     }
 
-    println!(
-        "Day 12 Part 1: no={}, maybe={}, yes={}, maybe+yes={}",
-        no,
-        maybe,
-        yes,
-        maybe + yes
-    );
+    info!("no={no}, maybe={maybe}, yes={yes}");
+
+    maybe + yes
 }
 
-register_day!(2025, 12, part1);
+register_day!(2025, 12, part1; Metadata {
+    title: "Christmas Tree Farm",
+    tags: &[Tag::Geometry],
+    notes: "Only bounds each region by area; regions that are neither clearly too small nor clearly big enough are counted as fitting",
+    ..Metadata::EMPTY
+});
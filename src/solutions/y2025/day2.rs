@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day};
 
 fn parse_input(input: &str) -> Vec<(i64, i64)> {
     input
@@ -8,7 +8,7 @@ fn parse_input(input: &str) -> Vec<(i64, i64)> {
         .collect()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> i64 {
     let mut total = 0;
     for num in parse_input(input)
         .into_iter()
@@ -21,7 +21,7 @@ fn part1(input: &str) {
         }
     }
 
-    println!("Day 2 Part 1: {}", total);
+    total
 }
 
 fn substrings(s: &str) -> impl Iterator<Item = &str> {
@@ -29,7 +29,7 @@ fn substrings(s: &str) -> impl Iterator<Item = &str> {
     (1..=half_len).map(move |i| &s[..i])
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i64 {
     let mut total = 0;
     for num in parse_input(input)
         .into_iter()
@@ -45,7 +45,12 @@ fn part2(input: &str) {
         }
     }
 
-    println!("Day 2 Part 2: {}", total);
+    total
 }
 
-register_day!(2025, 2, part1, part2);
+register_day!(2025, 2, part1, part2; Metadata {
+    title: "Gift Shop",
+    tags: &[Tag::Math],
+    techniques: &["repeated substrings"],
+    ..Metadata::EMPTY
+});
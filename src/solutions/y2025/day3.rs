@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, register_day};
 
 fn parse_input(input: &str) -> Vec<Vec<i64>> {
     input
@@ -11,7 +11,7 @@ fn parse_input(input: &str) -> Vec<Vec<i64>> {
         .collect()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> i64 {
     let mut total = 0;
     for seq in parse_input(input) {
         // Find the first occurrence of the maximum value.
@@ -27,10 +27,10 @@ fn part1(input: &str) {
         total += format!("{}{}", val1, val2).parse::<i64>().unwrap();
     }
 
-    println!("Day 3 Part 1: {}", total);
+    total
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i64 {
     let mut total = 0;
 
     for seq in parse_input(input) {
@@ -57,7 +57,11 @@ fn part2(input: &str) {
             .unwrap();
     }
 
-    println!("Day 3 Part 2: {}", total);
+    total
 }
 
-register_day!(2025, 3, part1, part2);
+register_day!(2025, 3, part1, part2; Metadata {
+    title: "Lobby",
+    techniques: &["greedy"],
+    ..Metadata::EMPTY
+});
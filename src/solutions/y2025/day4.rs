@@ -1,5 +1,5 @@
-use crate::register_day;
-use crate::utils::{GridUtils, Loc, DIR8};
+use crate::utils::{DIR8, GridUtils, Loc};
+use crate::{Metadata, Tag, register_day};
 use grid::Grid;
 
 fn parse_input(input: &str) -> Grid<char> {
@@ -13,22 +13,19 @@ fn adjacent_count(grid: &Grid<char>, loc: Loc) -> usize {
         .count()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let grid = parse_input(input);
 
-    let count = grid
-        .indexed_iter()
+    grid.indexed_iter()
         .filter(|(_, c)| **c == '@')
         .filter(|((lr, lc), _)| {
             let loc = Loc(*lr as isize, *lc as isize);
             adjacent_count(&grid, loc) < 4
         })
-        .count();
-
-    println!("Day 4 Part 1: {}", count);
+        .count()
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> usize {
     let mut grid = parse_input(input);
     let mut count = 0;
 
@@ -55,7 +52,12 @@ fn part2(input: &str) {
         }
     }
 
-    println!("Day 4 Part 2: {}", count);
+    count
 }
 
-register_day!(2025, 4, part1, part2);
+register_day!(2025, 4, part1, part2; Metadata {
+    title: "Printing Department",
+    tags: &[Tag::Grid, Tag::Simulation],
+    techniques: &["GridUtils", "DIR8"],
+    ..Metadata::EMPTY
+});
@@ -1,4 +1,4 @@
-use crate::register_day;
+use crate::{Metadata, Tag, register_day, register_generator};
 
 struct Range {
     start: i64,
@@ -42,15 +42,13 @@ fn parse_input(input: &str) -> (Vec<Range>, Vec<i64>) {
     (ranges, idxs)
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let (ranges, idxs) = parse_input(input);
-    let count = idxs
-        .iter()
+    idxs.iter()
         .filter(|&&idx| ranges.iter().any(|range| range.contains(idx)))
-        .count();
-    println!("Day 5 Part 1: {}", count);
+        .count()
 }
-fn part2(input: &str) {
+fn part2(input: &str) -> i64 {
     let (mut ranges, _) = parse_input(input);
     ranges.sort_by_key(|r| r.start);
 
@@ -66,7 +64,29 @@ fn part2(input: &str) {
     }
 
     let total_count: i64 = merged.iter().map(|r| r.count()).sum();
-    println!("Day 5 Part 2: {}", total_count);
+    total_count
 }
 
-register_day!(2025, 5, part1, part2);
+/// `size` possibly overlapping fresh ranges followed by `size` ingredient ids
+fn generate(rng: &mut fastrand::Rng, size: usize) -> String {
+    const MAX: i64 = 100_000_000_000_000;
+    let ranges: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let start = rng.i64(1..MAX);
+            let end = (start + rng.i64(0..MAX / 100)).min(MAX);
+            format!("{start}-{end}")
+        })
+        .collect();
+    let ids: Vec<String> = (0..size.max(1))
+        .map(|_| rng.i64(1..=MAX).to_string())
+        .collect();
+    format!("{}\n\n{}", ranges.join("\n"), ids.join("\n"))
+}
+
+register_day!(2025, 5, part1, part2; Metadata {
+    title: "Cafeteria",
+    tags: &[Tag::Geometry],
+    techniques: &["range merging"],
+    ..Metadata::EMPTY
+});
+register_generator!(2025, 5, generate);
@@ -1,6 +1,6 @@
 use grid::Grid;
 
-use crate::{register_day, utils::GridUtils};
+use crate::{Metadata, Tag, register_day, utils::GridUtils};
 
 fn parse_input(input: &str) -> (Grid<u64>, Vec<char>) {
     let (num_lines, ops_line) = input.trim().rsplit_once('\n').unwrap();
@@ -22,20 +22,17 @@ fn parse_input(input: &str) -> (Grid<u64>, Vec<char>) {
     (grid, ops)
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> u64 {
     let (grid, ops) = parse_input(input);
 
-    let total = grid
-        .iter_cols()
+    grid.iter_cols()
         .enumerate()
         .map(|(col_idx, col)| match ops[col_idx] {
             '+' => col.sum::<u64>(),
             '*' => col.product::<u64>(),
             _ => 0,
         })
-        .sum::<u64>();
-
-    println!("Day 6 Part 1: {}", total);
+        .sum::<u64>()
 }
 
 fn parse_input2(input: &str) -> (Vec<Vec<u64>>, Vec<char>) {
@@ -67,20 +64,22 @@ fn parse_input2(input: &str) -> (Vec<Vec<u64>>, Vec<char>) {
     (nums, ops)
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> u64 {
     let (nums, ops) = parse_input2(input);
 
-    let total = nums
-        .into_iter()
+    nums.into_iter()
         .enumerate()
         .map(|(col_idx, col)| match ops[col_idx] {
             '+' => col.into_iter().sum::<u64>(),
             '*' => col.into_iter().product::<u64>(),
             _ => 0,
         })
-        .sum::<u64>();
-
-    println!("Day 6 Part 2: {}", total);
+        .sum::<u64>()
 }
 
-register_day!(2025, 6, part1, part2);
+register_day!(2025, 6, part1, part2; Metadata {
+    title: "Trash Compactor",
+    tags: &[Tag::Parsing, Tag::Grid],
+    techniques: &["GridUtils"],
+    ..Metadata::EMPTY
+});
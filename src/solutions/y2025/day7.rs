@@ -2,8 +2,9 @@ use grid::Grid;
 use hashbrown::{HashMap, HashSet};
 
 use crate::{
-    register_day,
+    Metadata, Tag, register_day,
     utils::{Dir, GridUtils, Loc},
+    visualize::{self, Color, Frame},
 };
 
 fn parse_input(input: &str) -> (Grid<char>, HashSet<Loc>) {
@@ -12,7 +13,7 @@ fn parse_input(input: &str) -> (Grid<char>, HashSet<Loc>) {
     (manifold, beam_starts)
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> i32 {
     let (manifold, mut beam_heads) = parse_input(input);
 
     let mut split_count = 0;
@@ -34,12 +35,15 @@ fn part1(input: &str) {
             }
         }
         beam_heads = next_beam_heads;
+        visualize::animate(|| {
+            Frame::from_grid(&manifold).highlight(beam_heads.iter().copied(), Color::Yellow)
+        });
     }
 
-    println!("Day 7 Part 1: {}", split_count);
+    split_count
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> u64 {
     let (manifold, beam_heads) = parse_input(input);
     let mut beam_heads = HashMap::from_iter(beam_heads.into_iter().map(|loc| (loc, 1u64)));
 
@@ -63,11 +67,17 @@ fn part2(input: &str) {
             }
         }
         beam_heads = next_beam_heads;
+        visualize::animate(|| {
+            Frame::from_grid(&manifold).highlight(beam_heads.keys().copied(), Color::Yellow)
+        });
     }
 
-    let timeline_count = beam_heads.values().sum::<u64>();
-
-    println!("Day 7 Part 2: {}", timeline_count);
+    beam_heads.values().sum::<u64>()
 }
 
-register_day!(2025, 7, part1, part2);
+register_day!(2025, 7, part1, part2; Metadata {
+    title: "Laboratories",
+    tags: &[Tag::Grid, Tag::Dp],
+    techniques: &["count map", "visualize"],
+    ..Metadata::EMPTY
+});
@@ -1,10 +1,10 @@
 use std::hash::Hash;
 
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use itertools::Itertools;
 use ordered_float::OrderedFloat;
 
-use crate::{Opt, register_day};
+use crate::{Metadata, Tag, register_day, register_generator};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Loc3D {
@@ -76,9 +76,10 @@ fn parse_input(input: &str) -> Vec<Loc3D> {
         .collect()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let box_locs = parse_input(input);
-    let num_merges = if Opt::get().real { 1000 } else { 10 };
+    // Detect if using example (20 boxes) or real input
+    let num_merges = if box_locs.len() <= 20 { 10 } else { 1000 };
     let closest_pairs = box_locs
         .clone()
         .into_iter()
@@ -106,10 +107,10 @@ fn part1(input: &str) {
         .take(3)
         .product();
 
-    println!("Day 8 Part 1: {}", prod);
+    prod
 }
 
-fn part2(input: &str) {
+fn part2(input: &str) -> i32 {
     let box_locs = parse_input(input);
 
     let loc_to_idx: HashMap<&Loc3D, usize> = box_locs
@@ -133,7 +134,30 @@ fn part2(input: &str) {
         })
         .expect("How did you get here?");
 
-    println!("Day 8 Part 2: {}", loc_a.x * loc_b.x);
+    loc_a.x * loc_b.x
 }
 
-register_day!(2025, 8, part1, part2);
+/// `size` distinct junction boxes in the same coordinate range as the real input
+fn generate(rng: &mut fastrand::Rng, size: usize) -> String {
+    let mut seen = HashSet::new();
+    let mut lines = Vec::new();
+    while lines.len() < size.max(2) {
+        let loc = (
+            rng.i32(0..100_000),
+            rng.i32(0..100_000),
+            rng.i32(0..100_000),
+        );
+        if seen.insert(loc) {
+            lines.push(format!("{},{},{}", loc.0, loc.1, loc.2));
+        }
+    }
+    lines.join("\n")
+}
+
+register_day!(2025, 8, part1, part2; Metadata {
+    title: "Playground",
+    tags: &[Tag::Geometry, Tag::Graph],
+    techniques: &["union-find"],
+    ..Metadata::EMPTY
+});
+register_generator!(2025, 8, generate);
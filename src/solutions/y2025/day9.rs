@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-use crate::{register_day, utils::Loc};
+use crate::{Metadata, Tag, register_day, utils::Loc};
 
 fn parse_input(input: &str) -> Vec<Loc> {
     input
@@ -10,22 +10,24 @@ fn parse_input(input: &str) -> Vec<Loc> {
         .collect()
 }
 
-fn part1(input: &str) {
+fn part1(input: &str) -> usize {
     let tiles = parse_input(input);
 
-    let area = tiles
+    tiles
         .iter()
         .tuple_combinations()
         .map(|(loc1, loc2)| (loc1.0.abs_diff(loc2.0) + 1) * (loc1.1.abs_diff(loc2.1) + 1))
         .max()
-        .unwrap();
-
-    println!("Day 9 Part 1: {}", area);
+        .unwrap()
 }
 
-fn _part2(input: &str) {
+fn _part2(input: &str) -> &str {
     let _data = parse_input(input);
-    println!("Day 9 Part 2: {}", "TODO");
+    "TODO"
 }
 
-register_day!(2025, 9, part1);
+register_day!(2025, 9, part1; Metadata {
+    title: "Movie Theater",
+    tags: &[Tag::Geometry],
+    ..Metadata::EMPTY
+});
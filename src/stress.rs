@@ -0,0 +1,220 @@
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use tabled::{Table, Tabled, settings::Style};
+
+use crate::{
+    Answer, DEFAULT_VARIANT, DaySolution, GeneratorFn, Opt, get_generator, get_solution,
+    time_solution,
+};
+
+/// Inputs are this size unless `--size` says otherwise
+const DEFAULT_FUZZ_SIZE: usize = 20;
+/// Largest input `--scale` times unless `--size` says otherwise
+const DEFAULT_SCALE_SIZE: usize = 512;
+/// Number of doublings `--scale` times, ending at the largest size
+const SCALE_STEPS: u32 = 7;
+/// Runs per size for `--scale`; the fastest one is reported
+const SCALE_RUNS: usize = 3;
+const BAR_WIDTH: usize = 30;
+
+#[derive(Tabled)]
+struct FailureRow {
+    #[tabled(rename = "Seed")]
+    seed: u64,
+    #[tabled(rename = "Part")]
+    part: u8,
+    #[tabled(rename = "Variant")]
+    variant: &'static str,
+    #[tabled(rename = "Result")]
+    result: String,
+}
+
+#[derive(Tabled)]
+struct ScaleRow {
+    #[tabled(rename = "Part")]
+    part: u8,
+    #[tabled(rename = "Size")]
+    size: usize,
+    #[tabled(rename = "Time")]
+    time: String,
+    #[tabled(rename = "Growth")]
+    growth: String,
+    #[tabled(rename = "")]
+    bar: String,
+}
+
+/// Run every implementation of the selected parts on `runs` generated inputs and report
+/// whether they all agree and none of them panic. Failing inputs are saved under
+/// `out/fuzz/` and can be reproduced with `--fuzz 1 --seed <seed>`.
+pub fn fuzz(opt: &Opt, year: u16, day: u8, runs: usize) -> Result<bool> {
+    let (solution, generate, parts) = setup(opt, year, day)?;
+    let seed = opt.seed.unwrap_or_else(|| fastrand::u64(..));
+    let size = opt.size.unwrap_or(DEFAULT_FUZZ_SIZE);
+    for &part in &parts {
+        if solution.variants(part).len() < 2 {
+            println!("Part {part} has a single implementation; only checking for panics");
+        }
+    }
+
+    let mut rows = Vec::new();
+    let mut saved = Vec::new();
+    for case_seed in (0..runs as u64).map(|i| seed.wrapping_add(i)) {
+        let input = generate(&mut fastrand::Rng::with_seed(case_seed), size);
+        let mut failed = false;
+
+        for &part in &parts {
+            let results: Vec<_> = solution
+                .variants(part)
+                .into_iter()
+                .map(|(name, f)| (name, catch_panic(|| f(&input))))
+                .collect();
+
+            let first = results.first().and_then(|(_, r)| r.as_ref().ok());
+            let agree = results
+                .iter()
+                .all(|(_, r)| r.as_ref().ok().is_some_and(|a| Some(a) == first));
+            if agree {
+                continue;
+            }
+
+            failed = true;
+            rows.extend(results.into_iter().map(|(variant, result)| FailureRow {
+                seed: case_seed,
+                part,
+                variant,
+                result: result.map_or_else(|e| format!("panicked: {e}"), |a: Answer| a.0),
+            }));
+        }
+
+        if failed {
+            saved.push(save_input(year, day, case_seed, &input)?);
+        }
+    }
+
+    if rows.is_empty() {
+        println!("{runs} generated inputs of size {size} (seeds from {seed}): no disagreements");
+        return Ok(true);
+    }
+
+    println!("{}", Table::new(rows).with(Style::rounded()));
+    eprintln!(
+        "{} of {runs} generated inputs failed (size {size}); inputs saved to:",
+        saved.len()
+    );
+    for path in saved {
+        eprintln!("  {}", path.display());
+    }
+    Ok(false)
+}
+
+/// Time the selected parts on generated inputs of doubling size up to `--size` and print
+/// how the time grows, to expose solutions that scale worse than expected
+pub fn scale(opt: &Opt, year: u16, day: u8) -> Result<()> {
+    let (solution, generate, parts) = setup(opt, year, day)?;
+    let seed = opt.seed.unwrap_or_else(|| fastrand::u64(..));
+    let max_size = opt.size.unwrap_or(DEFAULT_SCALE_SIZE);
+    let variant = opt.variant.as_deref().unwrap_or(DEFAULT_VARIANT);
+
+    let mut sizes: Vec<usize> = (0..SCALE_STEPS)
+        .map(|step| max_size >> step)
+        .filter(|&size| size > 0)
+        .collect();
+    sizes.dedup();
+    sizes.reverse();
+
+    let mut rows = Vec::new();
+    for &part in &parts {
+        let f = solution
+            .variant(part, variant)
+            .with_context(|| format!("No variant `{variant}` for part {part}"))?;
+
+        let mut times: Vec<(usize, Duration)> = Vec::new();
+        for &size in &sizes {
+            let input = generate(&mut fastrand::Rng::with_seed(seed), size);
+            let time = (0..SCALE_RUNS)
+                .map(|_| time_solution(f, &input).1)
+                .min()
+                .unwrap();
+            times.push((size, time));
+        }
+
+        let slowest = times.iter().map(|&(_, t)| t).max().unwrap_or_default();
+        for (i, &(size, time)) in times.iter().enumerate() {
+            let growth = match i.checked_sub(1).map(|prev| times[prev]) {
+                Some((prev_size, prev_time)) if !prev_time.is_zero() => {
+                    let exponent = (time.as_secs_f64() / prev_time.as_secs_f64()).ln()
+                        / (size as f64 / prev_size as f64).ln();
+                    format!("n^{exponent:.2}")
+                }
+                _ => "-".to_string(),
+            };
+            let filled = (BAR_WIDTH as f64 * time.as_secs_f64()
+                / slowest.as_secs_f64().max(f64::EPSILON))
+            .round() as usize;
+            rows.push(ScaleRow {
+                part,
+                size,
+                time: format!("{time:?}"),
+                growth,
+                bar: "█".repeat(filled.max(1)),
+            });
+        }
+    }
+
+    println!("{}", Table::new(rows).with(Style::rounded()));
+    println!("Growth is the exponent k in time ~ size^k between consecutive sizes (seed {seed})");
+    Ok(())
+}
+
+/// The day's solution, generator and the parts to run
+fn setup(opt: &Opt, year: u16, day: u8) -> Result<(&'static DaySolution, GeneratorFn, Vec<u8>)> {
+    let Some(solution) = get_solution(year, day) else {
+        bail!("No solution found for {year} day {day}");
+    };
+    let Some(generate) = get_generator(year, day) else {
+        bail!("No input generator registered for {year} day {day}");
+    };
+
+    let parts: Vec<u8> = [1, 2]
+        .into_iter()
+        .filter(|&p| opt.part.is_none_or(|part| part == p) && solution.part(p).is_some())
+        .collect();
+    if parts.is_empty() {
+        bail!(
+            "Part {} not implemented for {year} day {day}",
+            opt.part.unwrap_or(0)
+        );
+    }
+    Ok((solution, generate, parts))
+}
+
+fn save_input(year: u16, day: u8, seed: u64, input: &str) -> Result<PathBuf> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("out")
+        .join("fuzz")
+        .join(format!("y{year}"));
+    fs::create_dir_all(&dir).context("creating fuzz output directory")?;
+    let path = dir.join(format!("day{day}_seed{seed}.txt"));
+    fs::write(&path, input).with_context(|| format!("writing {}", path.display()))?;
+    Ok(path)
+}
+
+/// Run `f`, returning the message of any panic instead of printing it
+pub(crate) fn catch_panic<T>(f: impl FnOnce() -> T) -> std::result::Result<T, String> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+
+    result.map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
@@ -0,0 +1,63 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde_json::{Value, json};
+
+/// Phase marks for the running part, in the order they were made
+static MARKS: Mutex<Vec<(&'static str, Instant)>> = Mutex::new(Vec::new());
+
+/// A named section of a part and how long it took
+#[derive(Clone, Debug)]
+pub struct Phase {
+    pub name: &'static str,
+    pub duration: Duration,
+}
+
+/// Mark the start of a named phase of the running part, ending the previous one.
+/// The runner prints how long each phase took under the part's total time.
+pub fn phase(name: &'static str) {
+    MARKS.lock().unwrap().push((name, Instant::now()));
+}
+
+/// Forget marks from a previous part
+pub(crate) fn reset() {
+    MARKS.lock().unwrap().clear();
+}
+
+/// The phases marked since the last [`reset`], with the last one ending at `end`
+pub(crate) fn take_phases(end: Instant) -> Vec<Phase> {
+    let marks = std::mem::take(&mut *MARKS.lock().unwrap());
+    marks
+        .iter()
+        .enumerate()
+        .map(|(i, &(name, start))| Phase {
+            name,
+            duration: marks.get(i + 1).map_or(end, |&(_, next)| next) - start,
+        })
+        .collect()
+}
+
+/// Render phases as indented lines with their share of `total`
+pub fn render_phases(phases: &[Phase], total: Duration) -> String {
+    let width = phases.iter().map(|p| p.name.len()).max().unwrap_or(0);
+    phases
+        .iter()
+        .map(|p| {
+            let share = 100.0 * p.duration.as_secs_f64() / total.as_secs_f64().max(f64::EPSILON);
+            format!(
+                "    {:<width$}  {:>12}  {share:>5.1}%",
+                p.name,
+                format!("{:?}", p.duration)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Phases as JSON objects with their name and duration in nanoseconds
+pub fn phases_json(phases: &[Phase]) -> Value {
+    phases
+        .iter()
+        .map(|p| json!({ "name": p.name, "duration_ns": p.duration.as_nanos() as u64 }))
+        .collect()
+}
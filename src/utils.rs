@@ -128,7 +128,7 @@ where
     T: From<char>,
 {
     fn parse(input: &str) -> Self {
-        let cols = input.lines().next().unwrap().len();
+        let cols = input.lines().next().map_or(0, str::len);
         let chars = input
             .lines()
             .flat_map(|line| line.chars())
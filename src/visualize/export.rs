@@ -0,0 +1,267 @@
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, bail};
+#[cfg(feature = "cli")]
+use clap::ValueEnum;
+use hashbrown::HashMap;
+
+use super::{Color, Frame};
+
+/// Width and height in pixels of one grid cell in exported images
+const CELL_PIXELS: usize = 4;
+
+/// File formats for exported visualizations
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(ValueEnum))]
+pub enum ExportFormat {
+    /// One PPM image per frame
+    Ppm,
+    /// One PNG image per frame
+    Png,
+    /// One SVG image per frame, a rectangle per cell
+    Svg,
+    /// One animated GIF per part
+    Gif,
+}
+
+/// Mapping from grid characters to RGB colours
+#[derive(Clone, Debug)]
+pub struct Palette(HashMap<char, [u8; 3]>);
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette(HashMap::from([
+            ('.', [24, 24, 32]),
+            (' ', [0, 0, 0]),
+            ('#', [200, 200, 200]),
+            ('O', [230, 190, 90]),
+            ('^', [220, 80, 80]),
+            ('|', [90, 160, 230]),
+            ('S', [90, 200, 90]),
+        ]))
+    }
+}
+
+impl Palette {
+    /// Parse a palette such as `#=ffffff,.=000000`, on top of the default palette
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut palette = Palette::default();
+        for entry in spec.split(',').filter(|e| !e.is_empty()) {
+            let mut chars = entry.chars();
+            let (Some(c), Some('='), hex) = (chars.next(), chars.next(), chars.as_str()) else {
+                bail!("palette entries must look like `c=RRGGBB`, got `{entry}`");
+            };
+            let rgb = u32::from_str_radix(hex, 16)
+                .ok()
+                .filter(|_| hex.len() == 6)
+                .with_context(|| format!("invalid colour `{hex}` for `{c}`"))?;
+            palette
+                .0
+                .insert(c, [(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8]);
+        }
+        Ok(palette)
+    }
+
+    /// Colour for a character; characters without an entry get a stable colour derived from them
+    fn color(&self, c: char) -> [u8; 3] {
+        self.0.get(&c).copied().unwrap_or_else(|| {
+            let h = (c as u32).wrapping_mul(2654435761);
+            [
+                64 + (h >> 24) as u8 % 192,
+                64 + (h >> 16) as u8 % 192,
+                64 + (h >> 8) as u8 % 192,
+            ]
+        })
+    }
+}
+
+impl Color {
+    fn rgb(&self) -> [u8; 3] {
+        match self {
+            Color::Red => [220, 50, 50],
+            Color::Green => [60, 200, 60],
+            Color::Yellow => [240, 220, 40],
+            Color::Blue => [60, 100, 240],
+            Color::Magenta => [220, 60, 220],
+            Color::Cyan => [60, 220, 220],
+            Color::White => [255, 255, 255],
+        }
+    }
+}
+
+type GifEncoder = gif::Encoder<BufWriter<File>>;
+
+/// Writes the frames of one part to files under `out/`
+pub(super) struct Exporter {
+    format: ExportFormat,
+    palette: Palette,
+    dir: PathBuf,
+    /// GIF delay between frames, in hundredths of a second
+    delay: u16,
+    frame_count: usize,
+    /// GIF encoder and its size in pixels, created on the first frame
+    gif: Option<(GifEncoder, (usize, usize))>,
+}
+
+impl Exporter {
+    pub(super) fn new(format: ExportFormat, palette: Palette, dir: PathBuf, fps: u32) -> Self {
+        Exporter {
+            format,
+            palette,
+            dir,
+            delay: (100 / fps.max(1)).max(1) as u16,
+            frame_count: 0,
+            gif: None,
+        }
+    }
+
+    pub(super) fn write(&mut self, frame: &Frame) -> Result<()> {
+        if frame.cells.first().is_none_or(Vec::is_empty) {
+            return Ok(());
+        }
+        self.frame_count += 1;
+
+        match self.format {
+            ExportFormat::Ppm => {
+                let path = self.frame_path("ppm")?;
+                let (width, height, rgb) = self.rasterize(frame);
+                let mut data = format!("P6\n{width} {height}\n255\n").into_bytes();
+                data.extend(rgb);
+                fs::write(&path, data).with_context(|| format!("writing {}", path.display()))?;
+            }
+            ExportFormat::Png => {
+                let path = self.frame_path("png")?;
+                let (width, height, rgb) = self.rasterize(frame);
+                let file =
+                    File::create(&path).with_context(|| format!("writing {}", path.display()))?;
+                let mut encoder =
+                    png::Encoder::new(BufWriter::new(file), width as u32, height as u32);
+                encoder.set_color(png::ColorType::Rgb);
+                encoder.set_depth(png::BitDepth::Eight);
+                encoder.write_header()?.write_image_data(&rgb)?;
+            }
+            ExportFormat::Svg => {
+                let path = self.frame_path("svg")?;
+                fs::write(&path, self.svg(frame))
+                    .with_context(|| format!("writing {}", path.display()))?;
+            }
+            ExportFormat::Gif => {
+                let (width, height, rgb) = self.rasterize(frame);
+                self.write_gif_frame(width, height, rgb)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Append a frame to the part's GIF. The animation takes the size of its first frame;
+    /// later frames are padded or cropped to fit.
+    fn write_gif_frame(&mut self, width: usize, height: usize, rgb: Vec<u8>) -> Result<()> {
+        if self.gif.is_none() {
+            if width > u16::MAX as usize || height > u16::MAX as usize {
+                bail!("frame is too large for a GIF ({width}x{height} pixels)");
+            }
+            fs::create_dir_all(self.dir.parent().unwrap())?;
+            let path = self.dir.with_extension("gif");
+            let file =
+                File::create(&path).with_context(|| format!("writing {}", path.display()))?;
+            let mut encoder =
+                gif::Encoder::new(BufWriter::new(file), width as u16, height as u16, &[])?;
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+            self.gif = Some((encoder, (width, height)));
+        }
+
+        let (encoder, (gif_width, gif_height)) = self.gif.as_mut().unwrap();
+        let mut fitted = vec![0; *gif_width * *gif_height * 3];
+        for y in 0..height.min(*gif_height) {
+            let w = width.min(*gif_width) * 3;
+            fitted[y * *gif_width * 3..][..w].copy_from_slice(&rgb[y * width * 3..][..w]);
+        }
+
+        let mut gif_frame =
+            gif::Frame::from_rgb_speed(*gif_width as u16, *gif_height as u16, &fitted, 10);
+        gif_frame.delay = self.delay;
+        encoder.write_frame(&gif_frame)?;
+
+        Ok(())
+    }
+
+    fn frame_path(&self, extension: &str) -> Result<PathBuf> {
+        // Start each run from an empty directory so no frames from a longer previous run remain
+        if self.frame_count == 1 && self.dir.exists() {
+            fs::remove_dir_all(&self.dir)
+                .with_context(|| format!("clearing {}", self.dir.display()))?;
+        }
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("creating {}", self.dir.display()))?;
+        Ok(self
+            .dir
+            .join(format!("frame_{:05}.{extension}", self.frame_count)))
+    }
+
+    /// Draw each cell as a square of `CELL_PIXELS`, returning (width, height, RGB bytes)
+    fn rasterize(&self, frame: &Frame) -> (usize, usize, Vec<u8>) {
+        let rows = frame.cells.len();
+        let cols = frame.cells.first().map_or(0, Vec::len);
+        let (width, height) = (cols * CELL_PIXELS, rows * CELL_PIXELS);
+
+        let mut rgb = vec![0; width * height * 3];
+        for (r, row) in frame.cells.iter().enumerate() {
+            for (c, &cell) in row.iter().enumerate() {
+                let color = self.cell_color(frame, r, c, cell);
+                for y in r * CELL_PIXELS..(r + 1) * CELL_PIXELS {
+                    for x in c * CELL_PIXELS..(c + 1) * CELL_PIXELS {
+                        rgb[(y * width + x) * 3..][..3].copy_from_slice(&color);
+                    }
+                }
+            }
+        }
+
+        (width, height, rgb)
+    }
+
+    /// Draw each cell as a `CELL_PIXELS` square `<rect>`
+    fn svg(&self, frame: &Frame) -> String {
+        let rows = frame.cells.len();
+        let cols = frame.cells.first().map_or(0, Vec::len);
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" shape-rendering=\"crispEdges\">\n",
+            cols * CELL_PIXELS,
+            rows * CELL_PIXELS
+        );
+        for (r, row) in frame.cells.iter().enumerate() {
+            for (c, &cell) in row.iter().enumerate() {
+                let [red, green, blue] = self.cell_color(frame, r, c, cell);
+                svg.push_str(&format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{CELL_PIXELS}\" height=\"{CELL_PIXELS}\" fill=\"#{red:02x}{green:02x}{blue:02x}\"/>\n",
+                    c * CELL_PIXELS,
+                    r * CELL_PIXELS
+                ));
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    fn cell_color(&self, frame: &Frame, r: usize, c: usize, cell: char) -> [u8; 3] {
+        match frame.highlights.get(&(r, c)) {
+            Some(highlight) => highlight.rgb(),
+            None => self.palette.color(cell),
+        }
+    }
+
+    /// Number of frames written so far
+    pub(super) fn frame_count(&self) -> usize {
+        self.frame_count
+    }
+
+    /// Where this part's frames end up
+    pub(super) fn output_path(&self) -> PathBuf {
+        match self.format {
+            ExportFormat::Gif => self.dir.with_extension("gif"),
+            _ => self.dir.clone(),
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use std::io::Write;
+#[cfg(feature = "cli")]
+use std::path::PathBuf;
+#[cfg(feature = "cli")]
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use grid::Grid;
+use hashbrown::{HashMap, HashSet};
+
+use crate::utils::Loc;
+
+#[cfg(feature = "cli")]
+mod export;
+
+#[cfg(feature = "cli")]
+use export::Exporter;
+#[cfg(feature = "cli")]
+pub use export::{ExportFormat, Palette};
+
+/// Draw frames to the terminal
+static TERMINAL: AtomicBool = AtomicBool::new(false);
+static FPS: AtomicU32 = AtomicU32::new(10);
+/// Export settings from `--export` and `--palette`
+#[cfg(feature = "cli")]
+static EXPORT: Mutex<Option<(ExportFormat, Palette)>> = Mutex::new(None);
+/// Exporter for the part that is currently running
+#[cfg(feature = "cli")]
+static EXPORTER: Mutex<Option<Exporter>> = Mutex::new(None);
+/// Whether `EXPORTER` is set, so `show` and `animate` don't take the lock in hot loops
+static EXPORTING: AtomicBool = AtomicBool::new(false);
+
+/// Set up visualization from `--visualize`, `--fps`, `--export` and `--palette`
+#[cfg(feature = "cli")]
+pub fn init(visualize: bool, fps: u32, export: Option<(ExportFormat, Palette)>) {
+    TERMINAL.store(visualize, Ordering::Relaxed);
+    FPS.store(fps.max(1), Ordering::Relaxed);
+    *EXPORT.lock().unwrap() = export;
+}
+
+/// Check whether frames are drawn or exported, i.e. `--visualize` or `--export` was passed
+pub fn enabled() -> bool {
+    TERMINAL.load(Ordering::Relaxed) || EXPORTING.load(Ordering::Relaxed)
+}
+
+/// Start exporting frames for a part to `out/y<year>/day<day>/part<part>`, if `--export` was passed
+#[cfg(feature = "cli")]
+pub fn start_part(year: u16, day: u8, part: u8) {
+    let Some((format, palette)) = EXPORT.lock().unwrap().clone() else {
+        return;
+    };
+
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("out")
+        .join(format!("y{year}"))
+        .join(format!("day{day}"))
+        .join(format!("part{part}"));
+    let fps = FPS.load(Ordering::Relaxed);
+    *EXPORTER.lock().unwrap() = Some(Exporter::new(format, palette, dir, fps));
+    EXPORTING.store(true, Ordering::Relaxed);
+}
+
+/// Finish exporting the current part's frames
+#[cfg(feature = "cli")]
+pub fn finish_part() {
+    EXPORTING.store(false, Ordering::Relaxed);
+    if let Some(exporter) = EXPORTER.lock().unwrap().take()
+        && exporter.frame_count() > 0
+    {
+        eprintln!(
+            "Exported {} frames to {}",
+            exporter.frame_count(),
+            exporter.output_path().display()
+        );
+    }
+}
+
+#[cfg(feature = "cli")]
+fn export(frame: &Frame) {
+    if !EXPORTING.load(Ordering::Relaxed) {
+        return;
+    }
+    if let Some(exporter) = EXPORTER.lock().unwrap().as_mut()
+        && let Err(e) = exporter.write(frame)
+    {
+        eprintln!("Failed to export frame: {e}");
+    }
+}
+
+/// Image export needs the `cli` feature's encoders; without it frames are only drawn
+#[cfg(not(feature = "cli"))]
+fn export(_frame: &Frame) {}
+
+/// Colours used for highlighted cells
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    /// ANSI background colour code
+    fn ansi_bg(&self) -> u8 {
+        match self {
+            Color::Red => 41,
+            Color::Green => 42,
+            Color::Yellow => 43,
+            Color::Blue => 44,
+            Color::Magenta => 45,
+            Color::Cyan => 46,
+            Color::White => 47,
+        }
+    }
+}
+
+/// A single picture of a puzzle state: a grid of characters plus highlighted cells
+pub struct Frame {
+    cells: Vec<Vec<char>>,
+    /// Location of the top left cell, for frames built from points
+    origin: Loc,
+    highlights: HashMap<(usize, usize), Color>,
+}
+
+impl Frame {
+    /// Build a frame of `rows` x `cols` cells from a function of (row, col)
+    pub fn from_fn<F>(rows: usize, cols: usize, f: F) -> Self
+    where
+        F: Fn(usize, usize) -> char,
+    {
+        Frame {
+            cells: (0..rows)
+                .map(|r| (0..cols).map(|c| f(r, c)).collect())
+                .collect(),
+            origin: Loc(0, 0),
+            highlights: HashMap::new(),
+        }
+    }
+
+    /// Build a frame from a character grid
+    pub fn from_grid(grid: &Grid<char>) -> Self {
+        Frame::from_fn(grid.rows(), grid.cols(), |r, c| grid[(r, c)])
+    }
+
+    /// Build a frame covering the bounding box of `points`, drawn as `#` on `.`
+    pub fn from_points(points: &HashSet<Loc>) -> Self {
+        let Some(min_r) = points.iter().map(|l| l.0).min() else {
+            return Frame::from_fn(0, 0, |_, _| '.');
+        };
+        let min_c = points.iter().map(|l| l.1).min().unwrap();
+        let max_r = points.iter().map(|l| l.0).max().unwrap();
+        let max_c = points.iter().map(|l| l.1).max().unwrap();
+
+        let mut frame = Frame::from_fn(
+            (max_r - min_r + 1) as usize,
+            (max_c - min_c + 1) as usize,
+            |r, c| {
+                if points.contains(&Loc(r as isize + min_r, c as isize + min_c)) {
+                    '#'
+                } else {
+                    '.'
+                }
+            },
+        );
+        frame.origin = Loc(min_r, min_c);
+        frame
+    }
+
+    /// Highlight the given locations, ignoring any outside the frame
+    pub fn highlight<I>(mut self, locs: I, color: Color) -> Self
+    where
+        I: IntoIterator<Item = Loc>,
+    {
+        let bounds = (self.cells.len(), self.cells.first().map_or(0, Vec::len));
+        for loc in locs {
+            let loc = loc - self.origin;
+            if loc.in_bounds(bounds) {
+                self.highlights.insert(loc.into(), color);
+            }
+        }
+        self
+    }
+
+    /// Render the frame with ANSI colours for highlighted cells
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for (r, row) in self.cells.iter().enumerate() {
+            for (c, &cell) in row.iter().enumerate() {
+                match self.highlights.get(&(r, c)) {
+                    Some(color) => {
+                        out.push_str(&format!("\x1B[1;{}m{cell}\x1B[0m", color.ansi_bg()))
+                    }
+                    None => out.push(cell),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Draw a frame to stderr if `--visualize` was passed, and export it if `--export` was passed.
+/// The frame is only built when visualization is enabled.
+pub fn show<F>(frame: F)
+where
+    F: FnOnce() -> Frame,
+{
+    if !enabled() {
+        return;
+    }
+
+    let frame = frame();
+    if TERMINAL.load(Ordering::Relaxed) {
+        eprintln!("{}", frame.render());
+    }
+    export(&frame);
+}
+
+/// Draw a frame of an animation to stderr if `--visualize` was passed,
+/// replacing the previous frame and waiting according to `--fps`.
+/// The frame is also exported if `--export` was passed.
+pub fn animate<F>(frame: F)
+where
+    F: FnOnce() -> Frame,
+{
+    if !enabled() {
+        return;
+    }
+
+    let frame = frame();
+    if TERMINAL.load(Ordering::Relaxed) {
+        let mut stderr = std::io::stderr().lock();
+        // Clear the screen and move the cursor to the top left
+        let _ = write!(stderr, "\x1B[2J\x1B[H{}", frame.render());
+        let _ = stderr.flush();
+        thread::sleep(Duration::from_secs(1) / FPS.load(Ordering::Relaxed));
+    }
+    export(&frame);
+}
@@ -0,0 +1,133 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+use std::{env, fs};
+
+use anyhow::{Context, Result, bail};
+use hashbrown::HashMap;
+use regex::Regex;
+
+use crate::manifest::Manifest;
+use crate::{Opt, make_path};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Rebuild and re-run the selected day whenever its source or input file changes,
+/// checking the answers against the answers manifest. Runs until interrupted.
+pub fn watch(opt: &Opt, year: u16, day: u8) -> Result<()> {
+    let source = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("solutions")
+        .join(format!("y{year}"))
+        .join(format!("day{day}.rs"));
+    if !source.exists() {
+        bail!(
+            "No source file for {year} day {day} at {}",
+            source.display()
+        );
+    }
+    let paths = [source, make_path(year, day, opt)];
+
+    // Re-run ourselves with the same arguments, minus --watch
+    let args: Vec<String> = env::args().skip(1).filter(|a| a != "--watch").collect();
+    let exe = env::current_exe().context("locating the runner executable")?;
+
+    let mut last_modified = modified_times(&paths);
+    let mut previous: HashMap<String, String> = HashMap::new();
+    // The manifest only has answers for examples
+    let example = (!opt.real).then(|| opt.alt.clone().unwrap_or_else(|| format!("day{day}")));
+    let target = (year, day, example.as_deref());
+
+    for path in &paths {
+        println!("Watching {}", path.display());
+    }
+    rebuild_and_run(&exe, &args, target, &mut previous)?;
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let modified = modified_times(&paths);
+        if modified == last_modified {
+            continue;
+        }
+        last_modified = modified;
+        rebuild_and_run(&exe, &args, target, &mut previous)?;
+    }
+}
+
+fn modified_times(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|p| fs::metadata(p).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+fn rebuild_and_run(
+    exe: &Path,
+    args: &[String],
+    (year, day, example): (u16, u8, Option<&str>),
+    previous: &mut HashMap<String, String>,
+) -> Result<()> {
+    println!("\n=== Building ===");
+
+    let mut build = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    build.arg("build").current_dir(env!("CARGO_MANIFEST_DIR"));
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+    // Rebuild with the features this runner was built with, not the defaults
+    build.arg("--no-default-features");
+    if !env!("AOC_FEATURES").is_empty() {
+        build.args(["--features", env!("AOC_FEATURES")]);
+    }
+    if !build.status().context("running cargo build")?.success() {
+        println!("=== Build failed, waiting for changes ===");
+        return Ok(());
+    }
+
+    let output = Command::new(exe)
+        .args(args)
+        .output()
+        .context("running solution")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    print!("{stdout}");
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+
+    let answers = parse_answers(&stdout);
+    let manifest = Manifest::load()?;
+    let expected = example
+        .and_then(|example| manifest.day(year, day)?.examples.get(example))
+        .cloned()
+        .unwrap_or_default();
+    if !answers.is_empty() {
+        println!("=== Answers ===");
+    }
+    for (part, answer) in answers {
+        let status = match previous.get(&part) {
+            None => String::new(),
+            Some(prev) if *prev == answer => " (unchanged)".to_string(),
+            Some(prev) => format!(" (was {prev})"),
+        };
+        let check = match part
+            .strip_prefix("Part ")
+            .and_then(|p| p.parse().ok())
+            .and_then(|p: u8| expected.get(&p)?.as_ref())
+        {
+            None => String::new(),
+            Some(expected) if *expected == answer => ", matches the manifest".to_string(),
+            Some(expected) => format!(", manifest expects {expected}"),
+        };
+        println!("{part}: {answer}{status}{check}");
+        previous.insert(part, answer);
+    }
+
+    Ok(())
+}
+
+/// Extract `("Part N", answer)` pairs from a run's output
+pub(crate) fn parse_answers(stdout: &str) -> Vec<(String, String)> {
+    let re = Regex::new(r"(?m)^Day \d+ (Part \d): (.*)$").unwrap();
+    re.captures_iter(stdout)
+        .map(|c| (c[1].to_string(), c[2].trim().to_string()))
+        .collect()
+}